@@ -40,6 +40,90 @@ pub enum AppError {
 
     #[error("Checked calculate failed")]
     CheckedCalculateFailed = 0xfa0a,
+
+    #[error("Invalid upgrade authority")]
+    InvalidUpgradeAuthority = 0xfa0b,
+
+    #[error("Invalid pending authority")]
+    InvalidPendingAuthority = 0xfa0c,
+
+    #[error("Missing role")]
+    MissingRole = 0xfa0d,
+
+    #[error("Role members full")]
+    RoleMembersFull = 0xfa0e,
+
+    #[error("Invalid multisig threshold")]
+    InvalidThreshold = 0xfa0f,
+
+    #[error("Not a multisig signer")]
+    InvalidMultisigSigner = 0xfa10,
+
+    #[error("Proposal already executed")]
+    ProposalExecuted = 0xfa11,
+
+    #[error("Proposal not approved")]
+    ProposalNotApproved = 0xfa12,
+
+    #[error("Invalid proposal accounts")]
+    InvalidProposalAccounts = 0xfa13,
+
+    #[error("Action has to go through the timelock")]
+    TimelockRequired = 0xfa14,
+
+    #[error("Timelock not ready")]
+    TimelockNotReady = 0xfa15,
+
+    #[error("Paused")]
+    Paused = 0xfa16,
+
+    #[error("Wallet is blocklisted")]
+    Blocklisted = 0xfa17,
+
+    #[error("Namespace mismatch")]
+    NamespaceMismatch = 0xfa18,
+
+    #[error("Minter allowance exceeded")]
+    AllowanceExceeded = 0xfa19,
+
+    #[error("Session key expired")]
+    SessionKeyExpired = 0xfa1a,
+
+    #[error("Not a guardian")]
+    InvalidGuardian = 0xfa1b,
+
+    #[error("Invalid recovery")]
+    InvalidRecovery = 0xfa1c,
+
+    #[error("Recovery not ready")]
+    RecoveryNotReady = 0xfa1d,
+
+    #[error("Token finalized")]
+    TokenFinalized = 0xfa1e,
+
+    #[error("Token data does not match mint")]
+    TokenMintMismatch = 0xfa1f,
+
+    #[error("Name too long")]
+    NameTooLong = 0xfa20,
+
+    #[error("Symbol too long")]
+    SymbolTooLong = 0xfa21,
+
+    #[error("Uri too long")]
+    UriTooLong = 0xfa22,
+
+    #[error("Invalid account type")]
+    InvalidAccountType = 0xfa23,
+
+    #[error("Invalid account version, run MigrateAccount")]
+    InvalidAccountVersion = 0xfa24,
+
+    #[error("Max supply exceeded")]
+    MaxSupplyExceeded = 0xfa25,
+
+    #[error("Rate limit exceeded")]
+    RateLimitExceeded = 0xfa26,
 }
 
 impl From<AppError> for ProgramError {
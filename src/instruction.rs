@@ -1,5 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
+    bpf_loader_upgradeable,
     instruction::{AccountMeta, Instruction},
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -16,6 +17,62 @@ pub enum AppInstruction {
     CreateToken(CreateTokenArgs),
     MintToken(MintArgs),
     BurnToken(BurnArgs),
+    ProposeAuthority(ProposeAuthorityArgs),
+    AcceptAuthority,
+    CancelAuthority,
+    GrantRole(RoleArgs),
+    RevokeRole(RoleArgs),
+    CreateMultisig(CreateMultisigArgs),
+    CreateProposal(CreateProposalArgs),
+    ApproveProposal,
+    ExecuteProposal,
+    ScheduleTimelock(TimelockAction),
+    CancelTimelock,
+    ExecuteTimelock,
+    Pause,
+    Unpause,
+    FreezeAccount,
+    ThawAccount,
+    AddToBlocklist(BlocklistArgs),
+    RemoveFromBlocklist(BlocklistArgs),
+    FreezeBlocklisted,
+    SetMinterAllowance(MinterAllowanceArgs),
+    RemoveMinterAllowance,
+    CreateSessionKey(SessionKeyArgs),
+    RevokeSessionKey,
+    SetGuardians(GuardiansArgs),
+    InitiateRecovery(RecoveryArgs),
+    ApproveRecovery,
+    VetoRecovery,
+    CompleteRecovery,
+    FinalizeToken,
+    EjectToken(EjectTokenArgs),
+    AdoptToken(AdoptTokenArgs),
+    UpdateToken(UpdateTokenArgs),
+    MigrateAccount,
+    CreateEmissionSchedule(EmissionScheduleArgs),
+    Crank,
+}
+
+impl From<ProposalAction> for AppInstruction {
+    fn from(action: ProposalAction) -> Self {
+        match action {
+            ProposalAction::Configure(args) => AppInstruction::Configure(args),
+            ProposalAction::MintToken(args) => AppInstruction::MintToken(args),
+            ProposalAction::BurnToken(args) => AppInstruction::BurnToken(args),
+            ProposalAction::ProposeAuthority(args) => AppInstruction::ProposeAuthority(args),
+            ProposalAction::AcceptAuthority => AppInstruction::AcceptAuthority,
+            ProposalAction::GrantRole(args) => AppInstruction::GrantRole(args),
+            ProposalAction::RevokeRole(args) => AppInstruction::RevokeRole(args),
+            ProposalAction::SetGuardians(args) => AppInstruction::SetGuardians(args),
+            ProposalAction::ScheduleTimelock(action) => AppInstruction::ScheduleTimelock(action),
+            ProposalAction::CancelAuthority => AppInstruction::CancelAuthority,
+            ProposalAction::CancelTimelock => AppInstruction::CancelTimelock,
+            ProposalAction::VetoRecovery => AppInstruction::VetoRecovery,
+            ProposalAction::FreezeAccount => AppInstruction::FreezeAccount,
+            ProposalAction::ThawAccount => AppInstruction::ThawAccount,
+        }
+    }
 }
 
 pub fn configure(
@@ -24,11 +81,14 @@ pub fn configure(
     config_info: &Pubkey,
     args: ConfigureArgs,
 ) -> Result<Instruction, ProgramError> {
+    let (program_data, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
     let accounts = vec![
         AccountMeta::new(*siger, true),
         AccountMeta::new(*config_info, false),
         AccountMeta::new_readonly(rent::id(), false),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(program_data, false),
     ];
 
     Ok(Instruction {
@@ -38,6 +98,8 @@ pub fn configure(
     })
 }
 
+/// `delegation` is the signer's session key or minter allowance when it lacks the minter role
+#[allow(clippy::too_many_arguments)]
 pub fn mint(
     program_id: &Pubkey,
     siger: &Pubkey,
@@ -47,9 +109,11 @@ pub fn mint(
     token_info: &Pubkey,
     mint_auth:  &Pubkey,
     token_program_info: &Pubkey, 
+    blocklist_entry: &Pubkey,
+    delegation: Option<&Pubkey>,
     args: MintArgs,
 ) -> Result<Instruction, ProgramError> {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*siger, true),
         AccountMeta::new(*config_info, false),
         AccountMeta::new(*mint_info, false),
@@ -59,7 +123,11 @@ pub fn mint(
         AccountMeta::new_readonly(*token_program_info, false),    
         AccountMeta::new_readonly(rent::id(), false),  
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*blocklist_entry, false),
     ];
+    if let Some(delegation) = delegation {
+        accounts.push(AccountMeta::new(*delegation, false));
+    }
     
     Ok(Instruction {
         program_id: *program_id,
@@ -68,9 +136,11 @@ pub fn mint(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_token(
     program_id: &Pubkey,
     siger: &Pubkey,
+    config_info: &Pubkey,
     token_info: &Pubkey,
     mint: &Pubkey,
     mint_auth:  &Pubkey,
@@ -80,8 +150,9 @@ pub fn create_token(
 ) -> Result<Instruction, ProgramError> {
     let accounts = vec![
         AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
         AccountMeta::new(*token_info, false),
-        AccountMeta::new(*mint, true),
+        AccountMeta::new(*mint, !args.pda_mint),
         AccountMeta::new(*mint_auth, false),
         AccountMeta::new(*metadata_key, false),
         AccountMeta::new_readonly(*metadata_program, false),
@@ -97,6 +168,47 @@ pub fn create_token(
     })
 }
 
+/// CreateToken with the mint and its dependent accounts derived from
+/// `siger` and `args.symbol`
+pub fn create_pda_token(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mut args: CreateTokenArgs,
+) -> Result<Instruction, ProgramError> {
+    args.pda_mint = true;
+    let (mint, _) = Pubkey::find_program_address(
+        &[
+            program_id.as_ref(),
+            siger.as_ref(),
+            args.symbol.as_bytes(),
+            "mint".as_bytes(),
+        ],
+        program_id,
+    );
+    let (token_info, _) = Pubkey::find_program_address(
+        &[program_id.as_ref(), mint.as_ref(), "token_info".as_bytes()],
+        program_id,
+    );
+    let (mint_auth, _) = Pubkey::find_program_address(
+        &[program_id.as_ref(), mint.as_ref(), "mint_auth".as_bytes()],
+        program_id,
+    );
+    let (metadata_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
+    create_token(
+        program_id,
+        siger,
+        config_info,
+        &token_info,
+        &mint,
+        &mint_auth,
+        &metadata_key,
+        &mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID,
+        args,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn burn(
     program_id: &Pubkey,
     siger: &Pubkey,
@@ -105,9 +217,11 @@ pub fn burn(
     token_account: &Pubkey,
     token_info: &Pubkey,
     token_program_info: &Pubkey, 
+    blocklist_entry: &Pubkey,
+    session_key: Option<&Pubkey>,
     args: BurnArgs,
 ) -> Result<Instruction, ProgramError> {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*siger, true),
         AccountMeta::new(*config_info, false),
         AccountMeta::new(*mint_info, false),
@@ -116,11 +230,940 @@ pub fn burn(
         AccountMeta::new_readonly(*token_program_info, false),    
         AccountMeta::new_readonly(rent::id(), false),  
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*blocklist_entry, false),
     ];
+    if let Some(session_key) = session_key {
+        accounts.push(AccountMeta::new(*session_key, false));
+    }
     
     Ok(Instruction {
         program_id: *program_id,
         accounts,
         data: AppInstruction::BurnToken(args).try_to_vec().unwrap(),
     })
-}
\ No newline at end of file
+}
+pub fn propose_authority(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    args: ProposeAuthorityArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::ProposeAuthority(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn accept_authority(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::AcceptAuthority.try_to_vec().unwrap(),
+    })
+}
+
+pub fn cancel_authority(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::CancelAuthority.try_to_vec().unwrap(),
+    })
+}
+
+pub fn grant_role(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    args: RoleArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::GrantRole(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn revoke_role(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    args: RoleArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::RevokeRole(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn create_multisig(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    multisig: &Pubkey,
+    args: CreateMultisigArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new(*multisig, false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::CreateMultisig(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn create_proposal(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    args: CreateProposalArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new(*multisig, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::CreateProposal(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn approve_proposal(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new_readonly(*multisig, false),
+        AccountMeta::new(*proposal, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::ApproveProposal.try_to_vec().unwrap(),
+    })
+}
+
+pub fn execute_proposal(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    proposal_accounts: &[ProposalAccount],
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new_readonly(*multisig, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new_readonly(*program_id, false),
+    ];
+    for account in proposal_accounts {
+        if account.is_writable {
+            accounts.push(AccountMeta::new(account.pubkey, false));
+        } else {
+            accounts.push(AccountMeta::new_readonly(account.pubkey, false));
+        }
+    }
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::ExecuteProposal.try_to_vec().unwrap(),
+    })
+}
+
+pub fn schedule_timelock(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    timelock: &Pubkey,
+    action: TimelockAction,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new(*config_info, false),
+        AccountMeta::new(*timelock, false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::ScheduleTimelock(action).try_to_vec().unwrap(),
+    })
+}
+
+pub fn cancel_timelock(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    timelock: &Pubkey,
+    proposer: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*timelock, false),
+        AccountMeta::new(*proposer, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::CancelTimelock.try_to_vec().unwrap(),
+    })
+}
+
+/// `token_owner` is the owner of the token account a queued mint pays out to,
+/// `creator` is the token creator that signs a queued eject
+pub fn execute_timelock(
+    program_id: &Pubkey,
+    config_info: &Pubkey,
+    timelock: &Pubkey,
+    proposer: &Pubkey,
+    action: &TimelockAction,
+    token_owner: Option<&Pubkey>,
+    creator: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(*config_info, false),
+        AccountMeta::new(*timelock, false),
+        AccountMeta::new(*proposer, false),
+    ];
+    if let TimelockAction::MintToken {
+        mint,
+        token_account,
+        ..
+    } = action
+    {
+        let (token_info, _) = Pubkey::find_program_address(
+            &[program_id.as_ref(), mint.as_ref(), "token_info".as_bytes()],
+            program_id,
+        );
+        let (mint_auth, _) = Pubkey::find_program_address(
+            &[program_id.as_ref(), mint.as_ref(), "mint_auth".as_bytes()],
+            program_id,
+        );
+        let token_owner = token_owner.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let (blocklist_entry, _) = Pubkey::find_program_address(
+            &[
+                program_id.as_ref(),
+                config_info.as_ref(),
+                token_owner.as_ref(),
+                "blocklist".as_bytes(),
+            ],
+            program_id,
+        );
+        accounts.extend([
+            AccountMeta::new(*mint, false),
+            AccountMeta::new(*token_account, false),
+            AccountMeta::new(token_info, false),
+            AccountMeta::new_readonly(mint_auth, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(blocklist_entry, false),
+        ]);
+    }
+    if let TimelockAction::SetRateLimit { mint, .. } = action {
+        let (token_info, _) = Pubkey::find_program_address(
+            &[program_id.as_ref(), mint.as_ref(), "token_info".as_bytes()],
+            program_id,
+        );
+        accounts.extend([
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(token_info, false),
+        ]);
+    }
+    if let TimelockAction::EjectToken { mint, .. } = action {
+        let (token_info, _) = Pubkey::find_program_address(
+            &[program_id.as_ref(), mint.as_ref(), "token_info".as_bytes()],
+            program_id,
+        );
+        let (mint_auth, _) = Pubkey::find_program_address(
+            &[program_id.as_ref(), mint.as_ref(), "mint_auth".as_bytes()],
+            program_id,
+        );
+        let (metadata_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(mint);
+        let creator = creator.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        accounts.extend([
+            AccountMeta::new(*creator, true),
+            AccountMeta::new(*mint, false),
+            AccountMeta::new(token_info, false),
+            AccountMeta::new_readonly(mint_auth, false),
+            AccountMeta::new(metadata_key, false),
+            AccountMeta::new_readonly(mint_auth, false),
+            AccountMeta::new_readonly(mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ]);
+    }
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::ExecuteTimelock.try_to_vec().unwrap(),
+    })
+}
+
+/// Pauses `mint` only, or every token of the config when it is `None`
+pub fn pause(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+    if let Some(mint) = mint {
+        let (token_info, _) = Pubkey::find_program_address(
+            &[program_id.as_ref(), mint.as_ref(), "token_info".as_bytes()],
+            program_id,
+        );
+        accounts.push(AccountMeta::new_readonly(*mint, false));
+        accounts.push(AccountMeta::new(token_info, false));
+    }
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::Pause.try_to_vec().unwrap(),
+    })
+}
+
+/// Unpauses `mint` only, or every token of the config when it is `None`
+pub fn unpause(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+    if let Some(mint) = mint {
+        let (token_info, _) = Pubkey::find_program_address(
+            &[program_id.as_ref(), mint.as_ref(), "token_info".as_bytes()],
+            program_id,
+        );
+        accounts.push(AccountMeta::new_readonly(*mint, false));
+        accounts.push(AccountMeta::new(token_info, false));
+    }
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::Unpause.try_to_vec().unwrap(),
+    })
+}
+
+pub fn freeze_account(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    mint_auth: &Pubkey,
+    token_account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    freeze_accounts(program_id, siger, config_info, mint, token_info, mint_auth, &[*token_account])
+}
+
+/// Freezes every account in `token_accounts` in one instruction
+pub fn freeze_accounts(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    mint_auth: &Pubkey,
+    token_accounts: &[Pubkey],
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_info, false),
+        AccountMeta::new_readonly(*mint_auth, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    accounts.extend(token_accounts.iter().map(|key| AccountMeta::new(*key, false)));
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::FreezeAccount.try_to_vec().unwrap(),
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn thaw_account(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    mint_auth: &Pubkey,
+    token_account: &Pubkey,
+    token_owner: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    thaw_accounts(
+        program_id,
+        siger,
+        config_info,
+        mint,
+        token_info,
+        mint_auth,
+        &[(*token_account, *token_owner)],
+    )
+}
+
+/// Thaws every `(token_account, owner)` in `token_accounts` in one instruction
+pub fn thaw_accounts(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    mint_auth: &Pubkey,
+    token_accounts: &[(Pubkey, Pubkey)],
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_info, false),
+        AccountMeta::new_readonly(*mint_auth, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for (token_account, owner) in token_accounts {
+        let (blocklist_entry, _) = Pubkey::find_program_address(
+            &[
+                program_id.as_ref(),
+                config_info.as_ref(),
+                owner.as_ref(),
+                "blocklist".as_bytes(),
+            ],
+            program_id,
+        );
+        accounts.push(AccountMeta::new_readonly(blocklist_entry, false));
+        accounts.push(AccountMeta::new(*token_account, false));
+    }
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::ThawAccount.try_to_vec().unwrap(),
+    })
+}
+
+pub fn add_to_blocklist(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    blocklist_entry: &Pubkey,
+    args: BlocklistArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*blocklist_entry, false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::AddToBlocklist(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn remove_from_blocklist(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    blocklist_entry: &Pubkey,
+    args: BlocklistArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*blocklist_entry, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::RemoveFromBlocklist(args).try_to_vec().unwrap(),
+    })
+}
+
+/// `holders` are (blocklist entry, token account) pairs
+pub fn freeze_blocklisted(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    mint_auth: &Pubkey,
+    holders: &[(Pubkey, Pubkey)],
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_info, false),
+        AccountMeta::new_readonly(*mint_auth, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for (blocklist_entry, token_account) in holders {
+        accounts.push(AccountMeta::new_readonly(*blocklist_entry, false));
+        accounts.push(AccountMeta::new(*token_account, false));
+    }
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::FreezeBlocklisted.try_to_vec().unwrap(),
+    })
+}
+
+pub fn set_minter_allowance(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    minter_allowance: &Pubkey,
+    args: MinterAllowanceArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*minter_allowance, false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::SetMinterAllowance(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn remove_minter_allowance(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    minter_allowance: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*minter_allowance, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::RemoveMinterAllowance.try_to_vec().unwrap(),
+    })
+}
+
+pub fn create_session_key(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    session_key: &Pubkey,
+    args: SessionKeyArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*session_key, false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::CreateSessionKey(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn revoke_session_key(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    session_key: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*session_key, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::RevokeSessionKey.try_to_vec().unwrap(),
+    })
+}
+
+pub fn set_guardians(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    args: GuardiansArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::SetGuardians(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn initiate_recovery(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    args: RecoveryArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::InitiateRecovery(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn approve_recovery(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::ApproveRecovery.try_to_vec().unwrap(),
+    })
+}
+
+pub fn veto_recovery(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*config_info, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::VetoRecovery.try_to_vec().unwrap(),
+    })
+}
+
+pub fn complete_recovery(
+    program_id: &Pubkey,
+    config_info: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![AccountMeta::new(*config_info, false)];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::CompleteRecovery.try_to_vec().unwrap(),
+    })
+}
+
+/// `update_authority` is only needed for metadata created before `mint_auth` held it
+#[allow(clippy::too_many_arguments)]
+pub fn finalize_token(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    mint_auth: &Pubkey,
+    metadata_key: &Pubkey,
+    update_authority: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let update_authority = update_authority.unwrap_or(mint_auth);
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new(*token_info, false),
+        AccountMeta::new_readonly(*mint_auth, false),
+        AccountMeta::new(*metadata_key, false),
+        AccountMeta::new_readonly(*update_authority, *update_authority != *mint_auth),
+        AccountMeta::new_readonly(mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::FinalizeToken.try_to_vec().unwrap(),
+    })
+}
+
+/// `update_authority` is only needed for metadata created before `mint_auth` held it
+#[allow(clippy::too_many_arguments)]
+pub fn eject_token(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    creator: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    mint_auth: &Pubkey,
+    metadata_key: &Pubkey,
+    update_authority: Option<&Pubkey>,
+    args: EjectTokenArgs,
+) -> Result<Instruction, ProgramError> {
+    let update_authority = update_authority.unwrap_or(mint_auth);
+    let accounts = vec![
+        AccountMeta::new_readonly(*siger, true),
+        AccountMeta::new(*creator, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new(*token_info, false),
+        AccountMeta::new_readonly(*mint_auth, false),
+        AccountMeta::new(*metadata_key, false),
+        AccountMeta::new_readonly(*update_authority, *update_authority != *mint_auth),
+        AccountMeta::new_readonly(mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::EjectToken(args).try_to_vec().unwrap(),
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn adopt_token(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    current_authority: &Pubkey,
+    config_info: &Pubkey,
+    token_info: &Pubkey,
+    mint: &Pubkey,
+    mint_auth: &Pubkey,
+    metadata_key: &Pubkey,
+    args: AdoptTokenArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*current_authority, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(*token_info, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(*mint_auth, false),
+        AccountMeta::new(*metadata_key, false),
+        AccountMeta::new_readonly(mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::AdoptToken(args).try_to_vec().unwrap(),
+    })
+}
+
+/// `update_authority` is only needed for metadata created before `mint_auth` held it
+#[allow(clippy::too_many_arguments)]
+pub fn update_token(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    mint_auth: &Pubkey,
+    metadata_key: &Pubkey,
+    update_authority: Option<&Pubkey>,
+    args: UpdateTokenArgs,
+) -> Result<Instruction, ProgramError> {
+    let update_authority = update_authority.unwrap_or(mint_auth);
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(*token_info, false),
+        AccountMeta::new_readonly(*mint_auth, false),
+        AccountMeta::new(*metadata_key, false),
+        AccountMeta::new_readonly(*update_authority, *update_authority != *mint_auth),
+        AccountMeta::new_readonly(mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::UpdateToken(args).try_to_vec().unwrap(),
+    })
+}
+
+pub fn migrate_account(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::MigrateAccount.try_to_vec().unwrap(),
+    })
+}
+
+pub fn create_emission_schedule(
+    program_id: &Pubkey,
+    siger: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    token_info: &Pubkey,
+    emission: &Pubkey,
+    args: EmissionScheduleArgs,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*siger, true),
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_info, false),
+        AccountMeta::new(*emission, false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::CreateEmissionSchedule(args).try_to_vec().unwrap(),
+    })
+}
+
+/// `recipient_owner` owns the schedule's recipient token account
+pub fn crank(
+    program_id: &Pubkey,
+    config_info: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    recipient_owner: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (token_info, _) = Pubkey::find_program_address(
+        &[program_id.as_ref(), mint.as_ref(), "token_info".as_bytes()],
+        program_id,
+    );
+    let (mint_auth, _) = Pubkey::find_program_address(
+        &[program_id.as_ref(), mint.as_ref(), "mint_auth".as_bytes()],
+        program_id,
+    );
+    let (emission, _) = Pubkey::find_program_address(
+        &[program_id.as_ref(), mint.as_ref(), "emission".as_bytes()],
+        program_id,
+    );
+    let (blocklist_entry, _) = Pubkey::find_program_address(
+        &[
+            program_id.as_ref(),
+            config_info.as_ref(),
+            recipient_owner.as_ref(),
+            "blocklist".as_bytes(),
+        ],
+        program_id,
+    );
+    let accounts = vec![
+        AccountMeta::new_readonly(*config_info, false),
+        AccountMeta::new(emission, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new(*recipient, false),
+        AccountMeta::new(token_info, false),
+        AccountMeta::new_readonly(mint_auth, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(rent::id(), false),
+        AccountMeta::new_readonly(blocklist_entry, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AppInstruction::Crank.try_to_vec().unwrap(),
+    })
+}
@@ -1,7 +1,7 @@
 use borsh::BorshDeserialize;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
-use crate::{instruction::*, state::MintArgs};
+use crate::instruction::*;
 
 pub mod configure;
 pub use configure::*;
@@ -15,6 +15,54 @@ pub use burn::*;
 pub mod create_token;
 pub use create_token::*;
 
+pub mod authority;
+pub use authority::*;
+
+pub mod role;
+pub use role::*;
+
+pub mod multisig;
+pub use multisig::*;
+
+pub mod timelock;
+pub use timelock::*;
+
+pub mod pause;
+pub use pause::*;
+
+pub mod freeze;
+pub use freeze::*;
+
+pub mod blocklist;
+pub use blocklist::*;
+
+pub mod allowance;
+pub use allowance::*;
+
+pub mod session;
+pub use session::*;
+
+pub mod recovery;
+pub use recovery::*;
+
+pub mod finalize;
+pub use finalize::*;
+
+pub mod eject;
+pub use eject::*;
+
+pub mod adopt;
+pub use adopt::*;
+
+pub mod update_token;
+pub use update_token::*;
+
+pub mod migrate;
+pub use migrate::*;
+
+pub mod emission;
+pub use emission::*;
+
 pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -38,6 +86,145 @@ pub fn process_instruction(
             msg!("Instruction: Burn");
             process_burn(program_id, accounts,args)
         }
-        
+        AppInstruction::ProposeAuthority(args) => {
+            msg!("Instruction: ProposeAuthority");
+            process_propose_authority(program_id, accounts, args)
+        }
+        AppInstruction::AcceptAuthority => {
+            msg!("Instruction: AcceptAuthority");
+            process_accept_authority(program_id, accounts)
+        }
+        AppInstruction::CancelAuthority => {
+            msg!("Instruction: CancelAuthority");
+            process_cancel_authority(program_id, accounts)
+        }
+        AppInstruction::GrantRole(args) => {
+            msg!("Instruction: GrantRole");
+            process_grant_role(program_id, accounts, args)
+        }
+        AppInstruction::RevokeRole(args) => {
+            msg!("Instruction: RevokeRole");
+            process_revoke_role(program_id, accounts, args)
+        }
+        AppInstruction::CreateMultisig(args) => {
+            msg!("Instruction: CreateMultisig");
+            process_create_multisig(program_id, accounts, args)
+        }
+        AppInstruction::CreateProposal(args) => {
+            msg!("Instruction: CreateProposal");
+            process_create_proposal(program_id, accounts, args)
+        }
+        AppInstruction::ApproveProposal => {
+            msg!("Instruction: ApproveProposal");
+            process_approve_proposal(program_id, accounts)
+        }
+        AppInstruction::ExecuteProposal => {
+            msg!("Instruction: ExecuteProposal");
+            process_execute_proposal(program_id, accounts)
+        }
+        AppInstruction::ScheduleTimelock(action) => {
+            msg!("Instruction: ScheduleTimelock");
+            process_schedule_timelock(program_id, accounts, action)
+        }
+        AppInstruction::CancelTimelock => {
+            msg!("Instruction: CancelTimelock");
+            process_cancel_timelock(program_id, accounts)
+        }
+        AppInstruction::ExecuteTimelock => {
+            msg!("Instruction: ExecuteTimelock");
+            process_execute_timelock(program_id, accounts)
+        }
+        AppInstruction::Pause => {
+            msg!("Instruction: Pause");
+            process_set_paused(program_id, accounts, true)
+        }
+        AppInstruction::Unpause => {
+            msg!("Instruction: Unpause");
+            process_set_paused(program_id, accounts, false)
+        }
+        AppInstruction::FreezeAccount => {
+            msg!("Instruction: FreezeAccount");
+            process_freeze_accounts(program_id, accounts, true)
+        }
+        AppInstruction::ThawAccount => {
+            msg!("Instruction: ThawAccount");
+            process_freeze_accounts(program_id, accounts, false)
+        }
+        AppInstruction::AddToBlocklist(args) => {
+            msg!("Instruction: AddToBlocklist");
+            process_add_to_blocklist(program_id, accounts, args)
+        }
+        AppInstruction::RemoveFromBlocklist(args) => {
+            msg!("Instruction: RemoveFromBlocklist");
+            process_remove_from_blocklist(program_id, accounts, args)
+        }
+        AppInstruction::FreezeBlocklisted => {
+            msg!("Instruction: FreezeBlocklisted");
+            process_freeze_blocklisted(program_id, accounts)
+        }
+        AppInstruction::SetMinterAllowance(args) => {
+            msg!("Instruction: SetMinterAllowance");
+            process_set_minter_allowance(program_id, accounts, args)
+        }
+        AppInstruction::RemoveMinterAllowance => {
+            msg!("Instruction: RemoveMinterAllowance");
+            process_remove_minter_allowance(program_id, accounts)
+        }
+        AppInstruction::CreateSessionKey(args) => {
+            msg!("Instruction: CreateSessionKey");
+            process_create_session_key(program_id, accounts, args)
+        }
+        AppInstruction::RevokeSessionKey => {
+            msg!("Instruction: RevokeSessionKey");
+            process_revoke_session_key(program_id, accounts)
+        }
+        AppInstruction::SetGuardians(args) => {
+            msg!("Instruction: SetGuardians");
+            process_set_guardians(program_id, accounts, args)
+        }
+        AppInstruction::InitiateRecovery(args) => {
+            msg!("Instruction: InitiateRecovery");
+            process_initiate_recovery(program_id, accounts, args)
+        }
+        AppInstruction::ApproveRecovery => {
+            msg!("Instruction: ApproveRecovery");
+            process_approve_recovery(program_id, accounts)
+        }
+        AppInstruction::VetoRecovery => {
+            msg!("Instruction: VetoRecovery");
+            process_veto_recovery(program_id, accounts)
+        }
+        AppInstruction::CompleteRecovery => {
+            msg!("Instruction: CompleteRecovery");
+            process_complete_recovery(program_id, accounts)
+        }
+        AppInstruction::FinalizeToken => {
+            msg!("Instruction: FinalizeToken");
+            process_finalize_token(program_id, accounts)
+        }
+        AppInstruction::EjectToken(args) => {
+            msg!("Instruction: EjectToken");
+            process_eject_token(program_id, accounts, args)
+        }
+        AppInstruction::AdoptToken(args) => {
+            msg!("Instruction: AdoptToken");
+            process_adopt_token(program_id, accounts, args)
+        }
+        AppInstruction::UpdateToken(args) => {
+            msg!("Instruction: UpdateToken");
+            process_update_token(program_id, accounts, args)
+        }
+        AppInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        AppInstruction::CreateEmissionSchedule(args) => {
+            msg!("Instruction: CreateEmissionSchedule");
+            process_create_emission_schedule(program_id, accounts, args)
+        }
+        AppInstruction::Crank => {
+            msg!("Instruction: Crank");
+            process_crank(program_id, accounts)
+        }
     }
 }
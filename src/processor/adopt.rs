@@ -0,0 +1,145 @@
+use borsh::BorshSerialize;
+use mpl_token_metadata::{accounts::Metadata, types::DataV2};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    sysvar,
+};
+use spl_token::{instruction::AuthorityType, state::Mint};
+
+use crate::{error::AppError, state::*, utils::*};
+
+/// Registers an existing mint, moving its mint and freeze authority from
+/// `current_authority` to the `mint_auth` PDA
+pub fn process_adopt_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: AdoptTokenArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let current_authority_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let token_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let mint_auth = next_account_info(account_info_iter)?;
+    let metadata_info = next_account_info(account_info_iter)?;
+    let metadata_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_signer(current_authority_info)?;
+    assert_eq_pubkey(
+        metadata_program_info,
+        &mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID,
+    )?;
+    assert_eq_pubkey(token_program_info, &spl_token::id())?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_token_fields(&args.name, &args.symbol, &args.uri)?;
+
+    let bump = assert_token_info(program_id, mint_info.key, token_info)?;
+    if !token_info.data_is_empty() {
+        return Err(AppError::AlreadyInitialized.into());
+    }
+    let auth_bump = assert_mint_authority(program_id, mint_info, mint_auth)?;
+    let auth_seeds = [
+        program_id.as_ref(),
+        mint_info.key.as_ref(),
+        "mint_auth".as_bytes(),
+        &[auth_bump],
+    ];
+    let (metadata_key, _) = Metadata::find_pda(mint_info.key);
+    assert_eq_pubkey(metadata_info, &metadata_key)?;
+
+    assert_owned_by(mint_info, &spl_token::id())?;
+    let mint = Mint::unpack(&mint_info.data.borrow())?;
+    if mint.mint_authority != COption::Some(*current_authority_info.key) {
+        return Err(AppError::InvalidEqPubkey.into());
+    }
+
+    let mut authority_types = vec![AuthorityType::MintTokens];
+    if mint.freeze_authority == COption::Some(*current_authority_info.key) {
+        authority_types.push(AuthorityType::FreezeAccount);
+    }
+    for authority_type in authority_types {
+        invoke(
+            &spl_token::instruction::set_authority(
+                token_program_info.key,
+                mint_info.key,
+                Some(mint_auth.key),
+                authority_type,
+                current_authority_info.key,
+                &[],
+            )?,
+            &[
+                token_program_info.clone(),
+                mint_info.clone(),
+                current_authority_info.clone(),
+            ],
+        )?;
+    }
+
+    if metadata_info.data_is_empty() {
+        msg!("create metadata");
+        let creators = vec![mpl_token_metadata::types::Creator {
+            address: *signer_info.key,
+            verified: true,
+            share: 100,
+        }];
+        let data = DataV2 {
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri,
+            seller_fee_basis_points: 0,
+            creators: Some(creators),
+            collection: None,
+            uses: None,
+        };
+        mpl_create_metadata(
+            mint_info,
+            metadata_info,
+            mint_auth,
+            signer_info,
+            metadata_program_info,
+            token_program_info,
+            system_info,
+            rent_info,
+            &auth_seeds,
+            data,
+        )?;
+    }
+
+    create_or_allocate_account_raw(
+        *program_id,
+        token_info,
+        rent_info,
+        system_info,
+        signer_info,
+        TokenData::LEN,
+        &[program_id.as_ref(), mint_info.key.as_ref(), "token_info".as_bytes(), &[bump]],
+    )?;
+    let mut token_data = TokenData {
+        account_type: AccountType::Token,
+        version: TokenData::VERSION,
+        ..Default::default()
+    };
+    token_data.creator = *signer_info.key;
+    token_data.decimals = mint.decimals;
+    token_data.name = args.name;
+    token_data.symbol = args.symbol;
+    token_data.mint = *mint_info.key;
+    token_data.supply = mint.supply;
+    token_data.namespace = config_data.namespace;
+    token_data.serialize(&mut &mut token_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+use crate::{error::AppError, state::*, utils::*};
+
+pub fn process_set_minter_allowance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: MinterAllowanceArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let allowance_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    let mint = args.mint.unwrap_or_default();
+    let bump = assert_minter_allowance(
+        program_id,
+        config_info.key,
+        &args.minter,
+        args.mint.as_ref(),
+        allowance_info,
+    )?;
+    if allowance_info.data_is_empty() {
+        create_or_allocate_account_raw(
+            *program_id,
+            allowance_info,
+            rent_info,
+            system_info,
+            signer_info,
+            MinterAllowance::LEN,
+            &[
+                program_id.as_ref(),
+                config_info.key.as_ref(),
+                args.minter.as_ref(),
+                mint.as_ref(),
+                "allowance".as_bytes(),
+                &[bump],
+            ],
+        )?;
+    }
+    assert_owned_by(allowance_info, program_id)?;
+
+    let allowance = MinterAllowance {
+        account_type: AccountType::MinterAllowance,
+        version: MinterAllowance::VERSION,
+        config: *config_info.key,
+        minter: args.minter,
+        mint: args.mint,
+        amount: args.amount,
+        remaining: args.amount,
+        reset_period: args.reset_period,
+        period_start: now_timestamp(),
+    };
+    allowance.serialize(&mut &mut allowance_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_remove_minter_allowance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let allowance_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    assert_owned_by(allowance_info, program_id)?;
+    let allowance = MinterAllowance::from_account_info(allowance_info)?;
+    assert_minter_allowance(
+        program_id,
+        config_info.key,
+        &allowance.minter,
+        allowance.mint.as_ref(),
+        allowance_info,
+    )?;
+
+    close_account(allowance_info, signer_info)
+}
+
+/// Charges `amount` base units minted by `minter_info` against its allowance
+pub fn consume_minter_allowance(
+    program_id: &Pubkey,
+    config_info: &AccountInfo,
+    minter_info: &AccountInfo,
+    mint_info: &AccountInfo,
+    allowance_info: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    assert_owned_by(allowance_info, program_id)?;
+    let mut allowance = MinterAllowance::from_account_info(allowance_info)?;
+    assert_minter_allowance(
+        program_id,
+        config_info.key,
+        minter_info.key,
+        allowance.mint.as_ref(),
+        allowance_info,
+    )?;
+    if allowance.mint.is_some_and(|mint| mint != *mint_info.key) {
+        return Err(AppError::InvalidEqPubkey.into());
+    }
+
+    if let Some(period) = allowance.reset_period.filter(|period| *period > 0) {
+        let now = now_timestamp();
+        let elapsed = now.saturating_sub(allowance.period_start);
+        if elapsed >= period {
+            allowance.period_start = now - elapsed % period;
+            allowance.remaining = allowance.amount;
+        }
+    }
+
+    allowance.remaining = allowance
+        .remaining
+        .checked_sub(amount)
+        .ok_or(AppError::AllowanceExceeded)?;
+    allowance.serialize(&mut &mut allowance_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
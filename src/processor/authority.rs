@@ -0,0 +1,66 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::{error::AppError, state::*, utils::*};
+
+pub fn process_propose_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: ProposeAuthorityArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+    if config_data.timelock_delay > 0 {
+        return Err(AppError::TimelockRequired.into());
+    }
+
+    config_data.pending_authority = Some(args.new_authority);
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_accept_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    if config_data.pending_authority != Some(*signer_info.key) {
+        return Err(AppError::InvalidPendingAuthority.into());
+    }
+
+    config_data.authority = *signer_info.key;
+    config_data.pending_authority = None;
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_cancel_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+    if config_data.pending_authority.is_none() {
+        return Err(AppError::InvalidPendingAuthority.into());
+    }
+
+    config_data.pending_authority = None;
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+use crate::{error::AppError, state::*, utils::*};
+
+pub fn process_add_to_blocklist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: BlocklistArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let blocklist_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_role(&config_data, Role::Compliance, signer_info)?;
+
+    let bump = assert_blocklist(program_id, config_info.key, &args.wallet, blocklist_info)?;
+    if !blocklist_info.data_is_empty() {
+        return Err(AppError::AlreadyInitialized.into());
+    }
+    create_or_allocate_account_raw(
+        *program_id,
+        blocklist_info,
+        rent_info,
+        system_info,
+        signer_info,
+        BlocklistEntry::LEN,
+        &[
+            program_id.as_ref(),
+            config_info.key.as_ref(),
+            args.wallet.as_ref(),
+            "blocklist".as_bytes(),
+            &[bump],
+        ],
+    )?;
+
+    let entry = BlocklistEntry {
+        account_type: AccountType::BlocklistEntry,
+        version: BlocklistEntry::VERSION,
+        config: *config_info.key,
+        wallet: args.wallet,
+    };
+    entry.serialize(&mut &mut blocklist_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_remove_from_blocklist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: BlocklistArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let blocklist_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_role(&config_data, Role::Compliance, signer_info)?;
+
+    assert_blocklist(program_id, config_info.key, &args.wallet, blocklist_info)?;
+    assert_owned_by(blocklist_info, program_id)?;
+
+    close_account(blocklist_info, signer_info)
+}
+
+/// Freezes the token accounts of blocklisted wallets, passed as
+/// (blocklist entry, token account) pairs after the fixed accounts
+pub fn process_freeze_blocklisted(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_info = next_account_info(account_info_iter)?;
+    let mint_auth = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let pairs = account_info_iter.as_slice();
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(token_program_info, &spl_token::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_role(&config_data, Role::Compliance, signer_info)?;
+
+    if pairs.is_empty() || !pairs.len().is_multiple_of(2) {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let token_data = load_token_data(program_id, mint_info, token_info)?;
+    assert_token_namespace(&config_data, &token_data)?;
+
+    let auth_bump = assert_mint_authority(program_id, mint_info, mint_auth)?;
+    let auth_seeds = [
+        program_id.as_ref(),
+        mint_info.key.as_ref(),
+        "mint_auth".as_bytes(),
+        &[auth_bump],
+    ];
+    for pair in pairs.chunks(2) {
+        let blocklist_info = &pair[0];
+        let token_account = &pair[1];
+
+        assert_owned_by(blocklist_info, program_id)?;
+        let entry = BlocklistEntry::from_account_info(blocklist_info)?;
+        assert_blocklist(program_id, config_info.key, &entry.wallet, blocklist_info)?;
+        let owner = spl_token::state::Account::unpack(&token_account.data.borrow())?.owner;
+        if owner != entry.wallet {
+            return Err(AppError::InvalidOwner.into());
+        }
+
+        spl_token_freeze_account(
+            token_program_info,
+            mint_info,
+            token_account,
+            mint_auth,
+            &auth_seeds,
+        )?;
+    }
+
+    Ok(())
+}
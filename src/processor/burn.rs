@@ -1,9 +1,8 @@
-use crate::{ferror, state::*, utils::*};
+use crate::{error::AppError, processor::consume_session_key, state::*, utils::*};
 use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
-    program_error::ProgramError,
     pubkey::Pubkey,
     sysvar,
 };
@@ -22,29 +21,40 @@ pub fn process_burn(
     let token_program_info = next_account_info(account_info_iter)?;
     let rent_info = next_account_info(account_info_iter)?;
     let system_info = next_account_info(account_info_iter)?;
+    let blocklist_info = next_account_info(account_info_iter)?;
+    let session_info = next_account_info(account_info_iter).ok();
 
-    assert_signer(&signer_info)?;
-    assert_eq_pubkey(&token_program_info, &spl_token::id())?;
-    assert_eq_pubkey(&rent_info, &sysvar::rent::id())?;
-    assert_eq_pubkey(&system_info, &solana_program::system_program::id())?;
-    assert_config(&program_id, &config_info)?;
-    let config_data = ConfigureData::from_account_info(config_info)?;
-    assert_eq_pubkey(&signer_info, &config_data.authority)?;
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(token_program_info, &spl_token::id())?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    // burners without the role act through a session key issued by the authority
+    let session_info = if config_data.has_role(Role::Burner, signer_info.key) {
+        None
+    } else {
+        Some(session_info.ok_or(AppError::MissingRole)?)
+    };
 
-    assert_token_info(program_id, &mint_info.key, token_info)?;
-
-    let mut token_data = TokenData::from_account_info(token_info)?;
-    let multiplier = (10 as u64).pow(token_data.decimals as u32) as u64;
-    let amt = args.amt.checked_mul(multiplier).ok_or(ProgramError::BorshIoError("mint amt error".into()))?;
+    let mut token_data = load_token_data(program_id, mint_info, token_info)?;
+    assert_token_namespace(&config_data, &token_data)?;
+    assert_not_paused(&config_data, &token_data)?;
+    assert_not_blocklisted(program_id, config_info.key, token_account, blocklist_info)?;
+    let amt = args.unit.to_base_units(args.amt, token_data.decimals)?;
     spl_token_burn(
         token_program_info,
         mint_info,
         token_account,
         signer_info,
         rent_info,
-        amt
+        amt,
+        token_data.decimals,
     )?;
     
+    if let Some(session_info) = session_info {
+        consume_session_key(program_id, config_info, signer_info, session_info, false, amt)?;
+    }
+
     token_data.supply -= amt;
     token_data.serialize(&mut *token_info.try_borrow_mut_data()?)?;
     Ok(())
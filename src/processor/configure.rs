@@ -8,7 +8,34 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::{ferror, state::*, utils::*};
+use crate::{error::AppError, ferror, state::*, utils::*};
+
+/// Applies `args` to an existing config, `timelocked` when run from the queue
+pub fn update_configure(
+    config_data: &mut ConfigureData,
+    args: ConfigureArgs,
+    timelocked: bool,
+) -> ProgramResult {
+    if config_data.authority != args.authority {
+        return ferror!("authority changes go through ProposeAuthority");
+    }
+    if config_data.namespace != args.namespace {
+        return Err(AppError::NamespaceMismatch.into());
+    }
+    let weakens = args.timelock_delay < config_data.timelock_delay
+        || match (config_data.large_mint_threshold, args.large_mint_threshold) {
+            (Some(current), Some(new)) => new > current,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+    if weakens && config_data.timelock_delay > 0 && !timelocked {
+        return Err(AppError::TimelockRequired.into());
+    }
+
+    config_data.timelock_delay = args.timelock_delay;
+    config_data.large_mint_threshold = args.large_mint_threshold;
+    Ok(())
+}
 
 pub fn process_configure(
     program_id: &Pubkey,
@@ -20,14 +47,20 @@ pub fn process_configure(
     let config_info = next_account_info(account_info_iter)?;
     let rent_info = next_account_info(account_info_iter)?;
     let system_info = next_account_info(account_info_iter)?;
+    let program_data_info = next_account_info(account_info_iter)?;
 
-    assert_eq_pubkey(&rent_info, &sysvar::rent::id())?;
-    assert_eq_pubkey(&system_info, &solana_program::system_program::id())?;
-    assert_signer(&signer_info)?;
-    let bump = assert_config(&program_id, &config_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    assert_signer(signer_info)?;
+    if args.namespace.len() > ConfigureArgs::MAX_NAMESPACE_LEN {
+        return ferror!("namespace too long");
+    }
+    let bump = assert_config(program_id, &args.namespace, config_info)?;
 
     let mut is_created = true;
     if config_info.data_is_empty() {
+        // only the upgrade authority may claim a fresh config
+        assert_upgrade_authority(program_id, program_data_info, signer_info)?;
         create_or_allocate_account_raw(
             *program_id,
             config_info,
@@ -38,23 +71,36 @@ pub fn process_configure(
             &[
                 program_id.as_ref(),
                 "config".as_bytes(),
+                args.namespace.as_bytes(),
                 &[bump],
             ],
         )?;
         is_created = false;
     }
 
-    let mut config_data = ConfigureData::from_account_info(config_info)?;
+    let mut config_data = if is_created {
+        ConfigureData::from_account_info(config_info)?
+    } else {
+        ConfigureData {
+            account_type: AccountType::Config,
+            version: ConfigureData::VERSION,
+            ..Default::default()
+        }
+    };
 
     if is_created {
         if config_data.authority != *signer_info.key {
             return ferror!("invalid authority");
         }
-        assert_owned_by(config_info, &program_id)?;
+        assert_owned_by(config_info, program_id)?;
+        update_configure(&mut config_data, args, false)?;
+    } else {
+        config_data.authority = args.authority;
+        config_data.timelock_delay = args.timelock_delay;
+        config_data.large_mint_threshold = args.large_mint_threshold;
+        config_data.namespace = args.namespace;
     }
 
-
-    config_data.authority = args.authority;
     config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
 
     Ok(())
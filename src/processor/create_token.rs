@@ -1,21 +1,15 @@
 use borsh::BorshSerialize;
-use mpl_token_metadata::{
-    instructions::{
-        CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs,
-    },
-    types::DataV2,
-};
+use mpl_token_metadata::types::DataV2;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     msg,
-    program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar,
 };
 
-use crate::{ferror, state::*, utils::*};
+use crate::{error::AppError, ferror, processor::update_token_metadata, state::*, utils::*};
 
 pub fn process_create_token(
     program_id: &Pubkey,
@@ -24,6 +18,7 @@ pub fn process_create_token(
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
     let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
     let token_info = next_account_info(account_info_iter)?;
     let mint = next_account_info(account_info_iter)?;
     let mint_auth = next_account_info(account_info_iter)?;
@@ -35,15 +30,17 @@ pub fn process_create_token(
     let system_info = next_account_info(account_info_iter)?;
 
     assert_eq_pubkey(
-        &metadata_program_info,
+        metadata_program_info,
         &mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID,
     )?;
-    assert_eq_pubkey(&rent_info, &sysvar::rent::id())?;
-    assert_eq_pubkey(&token_program_info, &spl_token::id())?;
-    assert_eq_pubkey(&system_info, &solana_program::system_program::id())?;
-    assert_signer(&signer_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(token_program_info, &spl_token::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    assert_signer(signer_info)?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_token_fields(&args.name, &args.symbol, &args.uri)?;
 
-    let bump = assert_token_info(program_id, &mint.key, token_info)?;
+    let bump = assert_token_info(program_id, mint.key, token_info)?;
     assert_mint_authority(program_id, mint, mint_auth)?;
 
     let auth_bump = assert_mint_authority(program_id, mint, mint_auth)?;
@@ -53,6 +50,43 @@ pub fn process_create_token(
         "mint_auth".as_bytes(),
         &[auth_bump],
     ];
+    //create mint
+    if args.pda_mint {
+        let mint_bump = assert_token_mint(program_id, signer_info.key, &args.symbol, mint)?;
+        if mint.data_is_empty() {
+            spl_token_create_mint(
+                token_program_info,
+                signer_info,
+                mint,
+                mint_auth,
+                &[
+                    program_id.as_ref(),
+                    signer_info.key.as_ref(),
+                    args.symbol.as_bytes(),
+                    "mint".as_bytes(),
+                    &[mint_bump],
+                ],
+                &[],
+                rent_info,
+                system_info,
+                args.decimals,
+            )?;
+        }
+    } else if mint.data_is_empty() {
+        assert_signer(mint)?;
+        spl_token_create_mint(
+            token_program_info,
+            signer_info,
+            mint,
+            mint_auth,
+            &[],
+            &[],
+            rent_info,
+            system_info,
+            args.decimals,
+        )?;
+    }
+
     //create token info
     let mut is_created = true;
     if token_info.data_is_empty() {
@@ -73,57 +107,85 @@ pub fn process_create_token(
             verified: true,
             share: 100,
         }];
-        let cmv3 = CreateMetadataAccountV3 {
-            metadata: *metadata_info.key,
-            mint: *mint.key,
-            mint_authority: *mint_auth.key,
-            payer: *signer_info.key,
-            update_authority: (*signer_info.key, true),
-            system_program: *system_info.key,
-            rent: Some(*rent_info.key),
-        };
         let data = DataV2 {
             name: args.name.clone(),
             symbol: args.symbol.clone(),
-            uri: args.uri,
+            uri: args.uri.clone(),
             seller_fee_basis_points: 0,
             creators: Some(creators),
             collection: None,
             uses: None,
         };
-        let cmv3_args = CreateMetadataAccountV3InstructionArgs {
-            data: data,
-            is_mutable: true,
-            collection_details: None,
-        };
-        invoke_signed(
-            &cmv3.instruction(cmv3_args),
-            &[
-                metadata_info.clone(),
-                mint.clone(),
-                signer_info.clone(),
-                mint_auth.clone(),
-                metadata_program_info.clone(),
-                token_program_info.clone(),
-                system_info.clone(),
-                rent_info.clone(),
-            ],
-            &[&auth_seeds]
+        mpl_create_metadata(
+            mint,
+            metadata_info,
+            mint_auth,
+            signer_info,
+            metadata_program_info,
+            token_program_info,
+            system_info,
+            rent_info,
+            &auth_seeds,
+            data,
         )?;
         is_created = false;
     }
 
-    let mut token_data = TokenData::from_account_info(token_info)?;
+    realloc_account(token_info, signer_info, system_info, TokenData::LEN)?;
+    let mut token_data = if is_created {
+        TokenData::from_account_info(token_info)?
+    } else {
+        TokenData {
+            account_type: AccountType::Token,
+            version: TokenData::VERSION,
+            ..Default::default()
+        }
+    };
     if is_created {
         assert_eq_pubkey(signer_info, &token_data.creator)?;
-        //update metadata todo
+        assert_token_namespace(&config_data, &token_data)?;
+        assert_token_mint_matches(&token_data, mint)?;
+        if token_data.finalized {
+            return Err(AppError::TokenFinalized.into());
+        }
+        // decimals are fixed once the token exists
+        if args.decimals != token_data.decimals {
+            return Err(AppError::TokenMintMismatch.into());
+        }
+        if args.max_supply != token_data.max_supply {
+            return ferror!("max supply is fixed");
+        }
+        // legacy metadata is still owned by the creator
+        let update_authority_info = if metadata_update_authority(mint, metadata_info)? == *signer_info.key {
+            signer_info
+        } else {
+            mint_auth
+        };
+        update_token_metadata(
+            mint,
+            metadata_info,
+            update_authority_info,
+            metadata_program_info,
+            mint_auth,
+            &auth_seeds,
+            UpdateTokenArgs {
+                name: args.name.clone(),
+                symbol: args.symbol.clone(),
+                uri: args.uri,
+            },
+        )?;
+    } else {
+        token_data.creator = *signer_info.key;
+        token_data.decimals = args.decimals;
+        token_data.mint = *mint.key;
+        token_data.namespace = config_data.namespace;
+        token_data.max_supply = args.max_supply;
+        // a mint created outside this instruction must agree with the args
+        assert_token_mint_matches(&token_data, mint)?;
     }
 
-    token_data.creator = signer_info.key.clone();
-    token_data.decimals = args.decimals;
     token_data.name = args.name;
     token_data.symbol = args.symbol;
-    token_data.mint = mint.key.clone();
     token_data.serialize(&mut &mut token_info.data.borrow_mut()[..])?;
 
     Ok(())
@@ -0,0 +1,135 @@
+use mpl_token_metadata::instructions::UpdateMetadataAccountV2InstructionArgs;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+use spl_token::{instruction::AuthorityType, state::Mint};
+
+use crate::{error::AppError, ferror, state::*, utils::*};
+
+/// Hands the mint, freeze and metadata authorities to `args.new_owner` and
+/// drops the token from the registry
+pub fn process_eject_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: EjectTokenArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_info = next_account_info(account_info_iter)?;
+    let mint_auth = next_account_info(account_info_iter)?;
+    let metadata_info = next_account_info(account_info_iter)?;
+    let update_authority_info = next_account_info(account_info_iter)?;
+    let metadata_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    eject_token_authorities(
+        program_id,
+        &config_data,
+        creator_info,
+        mint_info,
+        token_info,
+        mint_auth,
+        metadata_info,
+        update_authority_info,
+        metadata_program_info,
+        token_program_info,
+        args,
+        false,
+    )
+}
+
+/// Ejects the token once the authority and the creator agree, `timelocked`
+/// when run from the queue
+#[allow(clippy::too_many_arguments)]
+pub fn eject_token_authorities<'a>(
+    program_id: &Pubkey,
+    config_data: &ConfigureData,
+    creator_info: &AccountInfo<'a>,
+    mint_info: &AccountInfo<'a>,
+    token_info: &AccountInfo<'a>,
+    mint_auth: &AccountInfo<'a>,
+    metadata_info: &AccountInfo<'a>,
+    update_authority_info: &AccountInfo<'a>,
+    metadata_program_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    args: EjectTokenArgs,
+    timelocked: bool,
+) -> ProgramResult {
+    assert_signer(creator_info)?;
+    assert_eq_pubkey(
+        metadata_program_info,
+        &mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID,
+    )?;
+    assert_eq_pubkey(token_program_info, &spl_token::id())?;
+    // the new owner gets unlimited mint authority, holders get notice first
+    if config_data.timelock_delay > 0 && !timelocked {
+        return Err(AppError::TimelockRequired.into());
+    }
+
+    let token_data = load_token_data(program_id, mint_info, token_info)?;
+    assert_token_namespace(config_data, &token_data)?;
+    assert_eq_pubkey(creator_info, &token_data.creator)?;
+    if token_data.finalized {
+        return Err(AppError::TokenFinalized.into());
+    }
+    // the cap is only enforced while the program holds the mint authority
+    if token_data.max_supply.is_some() {
+        return ferror!("cannot eject a token with a max supply");
+    }
+
+    let auth_bump = assert_mint_authority(program_id, mint_info, mint_auth)?;
+    let auth_seeds = [
+        program_id.as_ref(),
+        mint_info.key.as_ref(),
+        "mint_auth".as_bytes(),
+        &[auth_bump],
+    ];
+    let mint = Mint::unpack(&mint_info.data.borrow())?;
+    spl_token_set_authority(
+        token_program_info,
+        mint_info,
+        mint_auth,
+        Some(&args.new_owner),
+        AuthorityType::MintTokens,
+        &auth_seeds,
+    )?;
+    if mint.freeze_authority == COption::Some(*mint_auth.key) {
+        spl_token_set_authority(
+            token_program_info,
+            mint_info,
+            mint_auth,
+            Some(&args.new_owner),
+            AuthorityType::FreezeAccount,
+            &auth_seeds,
+        )?;
+    }
+    mpl_update_metadata(
+        mint_info,
+        metadata_info,
+        update_authority_info,
+        metadata_program_info,
+        mint_auth,
+        &auth_seeds,
+        UpdateMetadataAccountV2InstructionArgs {
+            data: None,
+            new_update_authority: Some(args.new_owner),
+            primary_sale_happened: None,
+            is_mutable: None,
+        },
+    )?;
+
+    close_account(token_info, creator_info)
+}
@@ -0,0 +1,192 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+use crate::{error::AppError, ferror, processor::mint_token, state::*, utils::*};
+
+pub fn process_create_emission_schedule(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: EmissionScheduleArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_info = next_account_info(account_info_iter)?;
+    let emission_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    let token_data = load_token_data(program_id, mint_info, token_info)?;
+    assert_token_namespace(&config_data, &token_data)?;
+    if token_data.finalized {
+        return Err(AppError::TokenFinalized.into());
+    }
+    if args.period == 0 {
+        return ferror!("emission period must not be 0");
+    }
+    if let EmissionCurve::Halving {
+        halving_periods: 0, ..
+    } = args.curve
+    {
+        return ferror!("halving periods must not be 0");
+    }
+    // every curve peaks in its first period, a crank has to be able to mint it
+    let peak = AmountUnit::Ui.to_base_units(args.curve.amount(0), token_data.decimals)?;
+    let over_threshold = config_data.timelock_delay > 0
+        && config_data
+            .large_mint_threshold
+            .is_some_and(|threshold| peak > threshold);
+    let over_rate_limit = token_data
+        .rate_limit
+        .as_ref()
+        .is_some_and(|rate_limit| peak > rate_limit.max_amount);
+    if over_threshold || over_rate_limit {
+        return ferror!("one emission period mints more than a single mint may");
+    }
+
+    let bump = assert_emission_schedule(program_id, mint_info.key, emission_info)?;
+    if !emission_info.data_is_empty() {
+        return Err(AppError::AlreadyInitialized.into());
+    }
+    create_or_allocate_account_raw(
+        *program_id,
+        emission_info,
+        rent_info,
+        system_info,
+        signer_info,
+        EmissionSchedule::LEN,
+        &[
+            program_id.as_ref(),
+            mint_info.key.as_ref(),
+            "emission".as_bytes(),
+            &[bump],
+        ],
+    )?;
+
+    let schedule = EmissionSchedule {
+        account_type: AccountType::EmissionSchedule,
+        version: EmissionSchedule::VERSION,
+        config: *config_info.key,
+        mint: *mint_info.key,
+        recipient: args.recipient,
+        curve: args.curve,
+        start: args.start,
+        period: args.period,
+        emitted_periods: 0,
+    };
+    schedule.serialize(&mut &mut emission_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+/// Mints whatever vested since the last crank to the schedule's recipient
+pub fn process_crank(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_info = next_account_info(account_info_iter)?;
+    let emission_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_account = next_account_info(account_info_iter)?;
+    let token_info = next_account_info(account_info_iter)?;
+    let mint_auth = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let blocklist_info = next_account_info(account_info_iter)?;
+
+    let config_data = load_config(program_id, config_info)?;
+    assert_owned_by(emission_info, program_id)?;
+    let mut schedule = EmissionSchedule::from_account_info(emission_info)?;
+    assert_emission_schedule(program_id, &schedule.mint, emission_info)?;
+    assert_eq_pubkey(config_info, &schedule.config)?;
+    assert_eq_pubkey(mint_info, &schedule.mint)?;
+    assert_eq_pubkey(token_account, &schedule.recipient)?;
+
+    let token_data = load_token_data(program_id, mint_info, token_info)?;
+    let elapsed_periods = now_timestamp().saturating_sub(schedule.start) / schedule.period;
+    let last = elapsed_periods.min(
+        schedule
+            .emitted_periods
+            .saturating_add(EmissionSchedule::MAX_PERIODS_PER_CRANK),
+    );
+    if last <= schedule.emitted_periods {
+        return ferror!("nothing vested");
+    }
+    // stop before the batch would be refused, later cranks pick up the rest
+    let budget = crank_budget(&config_data, &token_data);
+    let mut amt: u64 = 0;
+    let mut end = schedule.emitted_periods;
+    while end < last {
+        let period_amt =
+            AmountUnit::Ui.to_base_units(schedule.curve.amount(end), token_data.decimals)?;
+        let total = amt
+            .checked_add(period_amt)
+            .ok_or(AppError::CheckedCalculateFailed)?;
+        if total > budget && end > schedule.emitted_periods {
+            break;
+        }
+        amt = total;
+        end += 1;
+    }
+    msg!("emit periods {} to {}", schedule.emitted_periods, end);
+    schedule.emitted_periods = end;
+    schedule.serialize(&mut &mut emission_info.data.borrow_mut()[..])?;
+
+    if amt > 0 {
+        mint_token(
+            program_id,
+            config_info,
+            &config_data,
+            mint_info,
+            token_account,
+            token_info,
+            mint_auth,
+            blocklist_info,
+            token_program_info,
+            rent_info,
+            MintArgs {
+                amt,
+                unit: AmountUnit::Raw,
+            },
+            false,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Base units one mint can take without hitting the large mint threshold or
+/// the rest of the current rate limit window
+fn crank_budget(config_data: &ConfigureData, token_data: &TokenData) -> u64 {
+    let mut budget = u64::MAX;
+    if config_data.timelock_delay > 0 {
+        if let Some(threshold) = config_data.large_mint_threshold {
+            budget = threshold;
+        }
+    }
+    if let Some(rate_limit) = &token_data.rate_limit {
+        let now = match rate_limit.unit {
+            WindowUnit::Slots => now_slot(),
+            WindowUnit::Seconds => now_timestamp(),
+        };
+        let minted = if now.saturating_sub(token_data.window_start) >= rate_limit.window {
+            0
+        } else {
+            token_data.window_minted
+        };
+        budget = budget.min(rate_limit.max_amount.saturating_sub(minted));
+    }
+    budget
+}
@@ -0,0 +1,90 @@
+use borsh::BorshSerialize;
+use mpl_token_metadata::instructions::UpdateMetadataAccountV2InstructionArgs;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+use spl_token::{instruction::AuthorityType, state::Mint};
+
+use crate::{error::AppError, utils::*};
+
+pub fn process_finalize_token(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_info = next_account_info(account_info_iter)?;
+    let mint_auth = next_account_info(account_info_iter)?;
+    let metadata_info = next_account_info(account_info_iter)?;
+    let update_authority_info = next_account_info(account_info_iter)?;
+    let metadata_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(
+        metadata_program_info,
+        &mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID,
+    )?;
+    assert_eq_pubkey(token_program_info, &spl_token::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    let mut token_data = load_token_data(program_id, mint_info, token_info)?;
+    assert_token_namespace(&config_data, &token_data)?;
+    if token_data.finalized {
+        return Err(AppError::TokenFinalized.into());
+    }
+
+    let auth_bump = assert_mint_authority(program_id, mint_info, mint_auth)?;
+    let auth_seeds = [
+        program_id.as_ref(),
+        mint_info.key.as_ref(),
+        "mint_auth".as_bytes(),
+        &[auth_bump],
+    ];
+    let mint = Mint::unpack(&mint_info.data.borrow())?;
+    spl_token_set_authority(
+        token_program_info,
+        mint_info,
+        mint_auth,
+        None,
+        AuthorityType::MintTokens,
+        &auth_seeds,
+    )?;
+    if mint.freeze_authority == COption::Some(*mint_auth.key) {
+        spl_token_set_authority(
+            token_program_info,
+            mint_info,
+            mint_auth,
+            None,
+            AuthorityType::FreezeAccount,
+            &auth_seeds,
+        )?;
+    }
+
+    let metadata = load_metadata(mint_info, metadata_info)?;
+    if metadata.is_mutable {
+        mpl_update_metadata(
+            mint_info,
+            metadata_info,
+            update_authority_info,
+            metadata_program_info,
+            mint_auth,
+            &auth_seeds,
+            UpdateMetadataAccountV2InstructionArgs {
+                data: None,
+                new_update_authority: None,
+                primary_sale_happened: None,
+                is_mutable: Some(false),
+            },
+        )?;
+    }
+
+    token_data.finalized = true;
+    token_data.serialize(&mut &mut token_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::*;
+
+/// Freezes or thaws every token account passed after the fixed accounts,
+/// each preceded by its owner's blocklist entry when thawing
+pub fn process_freeze_accounts(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    freeze: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_info = next_account_info(account_info_iter)?;
+    let mint_auth = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let token_accounts = account_info_iter.as_slice();
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(token_program_info, &spl_token::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    // thawing takes each token account's blocklist entry in front of it
+    let chunk_len = if freeze { 1 } else { 2 };
+    if token_accounts.is_empty() || !token_accounts.len().is_multiple_of(chunk_len) {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let token_data = load_token_data(program_id, mint_info, token_info)?;
+    assert_token_namespace(&config_data, &token_data)?;
+
+    let auth_bump = assert_mint_authority(program_id, mint_info, mint_auth)?;
+    let auth_seeds = [
+        program_id.as_ref(),
+        mint_info.key.as_ref(),
+        "mint_auth".as_bytes(),
+        &[auth_bump],
+    ];
+    for chunk in token_accounts.chunks(chunk_len) {
+        if freeze {
+            spl_token_freeze_account(
+                token_program_info,
+                mint_info,
+                &chunk[0],
+                mint_auth,
+                &auth_seeds,
+            )?;
+        } else {
+            // a compliance freeze holds until the owner leaves the blocklist
+            assert_not_blocklisted(program_id, config_info.key, &chunk[1], &chunk[0])?;
+            spl_token_thaw_account(
+                token_program_info,
+                mint_info,
+                &chunk[1],
+                mint_auth,
+                &auth_seeds,
+            )?;
+        }
+    }
+
+    Ok(())
+}
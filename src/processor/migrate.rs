@@ -0,0 +1,89 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{error::AppError, state::*, utils::*};
+
+/// Config layout before account headers
+#[derive(BorshDeserialize)]
+struct LegacyConfigureData {
+    authority: Pubkey,
+}
+
+impl LegacyConfigureData {
+    const LEN: usize = 32;
+}
+
+/// `token_info` layout before account headers
+#[derive(BorshDeserialize)]
+struct LegacyTokenData {
+    name: String,
+    symbol: String,
+    decimals: u8,
+    mint: Pubkey,
+    creator: Pubkey,
+    supply: u64,
+}
+
+impl LegacyTokenData {
+    const LEN: usize = 32 + 10 + 1 + 32 + 32 + 8;
+}
+
+/// Rewrites an account in an old layout to the current one, growing it as
+/// needed. Anyone can run it, the payer only funds the extra rent.
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let account_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(payer_info)?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    assert_owned_by(account_info, program_id)?;
+
+    match account_info.data_len() {
+        LegacyConfigureData::LEN => {
+            let legacy: LegacyConfigureData = try_from_slice_unchecked(&account_info.data.borrow())
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            // the only config that predates namespaces
+            assert_config(program_id, "", account_info)?;
+            msg!("migrate config");
+            let config_data = ConfigureData {
+                account_type: AccountType::Config,
+                version: ConfigureData::VERSION,
+                authority: legacy.authority,
+                ..Default::default()
+            };
+            realloc_account(account_info, payer_info, system_info, ConfigureData::LEN)?;
+            config_data.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        }
+        LegacyTokenData::LEN => {
+            let legacy: LegacyTokenData = try_from_slice_unchecked(&account_info.data.borrow())
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            assert_token_info(program_id, &legacy.mint, account_info)?;
+            msg!("migrate token {}", legacy.mint);
+            let token_data = TokenData {
+                account_type: AccountType::Token,
+                version: TokenData::VERSION,
+                name: legacy.name,
+                symbol: legacy.symbol,
+                decimals: legacy.decimals,
+                mint: legacy.mint,
+                creator: legacy.creator,
+                supply: legacy.supply,
+                ..Default::default()
+            };
+            realloc_account(account_info, payer_info, system_info, TokenData::LEN)?;
+            token_data.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        }
+        // every other account is already on its current layout
+        _ => return Err(AppError::InvalidAccountVersion.into()),
+    }
+
+    Ok(())
+}
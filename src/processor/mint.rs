@@ -1,12 +1,14 @@
-use crate::{ferror, state::*, utils::*};
+use crate::{error::AppError, processor::{consume_minter_allowance, consume_session_key}, state::*, utils::*};
 use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     program_error::ProgramError,
+    program_pack::Pack,
     pubkey::Pubkey,
     sysvar,
 };
+use spl_token::state::Mint;
 
 pub fn process_mint(
     program_id: &Pubkey,
@@ -23,26 +25,117 @@ pub fn process_mint(
     let token_program_info = next_account_info(account_info_iter)?;
     let rent_info = next_account_info(account_info_iter)?;
     let system_info = next_account_info(account_info_iter)?;
+    let blocklist_info = next_account_info(account_info_iter)?;
+    let delegation_info = next_account_info(account_info_iter).ok();
 
-    assert_signer(&signer_info)?;
-    assert_eq_pubkey(&token_program_info, &spl_token::id())?;
-    assert_eq_pubkey(&rent_info, &sysvar::rent::id())?;
-    assert_eq_pubkey(&system_info, &solana_program::system_program::id())?;
-    assert_config(&program_id, &config_info)?;
-    let config_data = ConfigureData::from_account_info(config_info)?;
-    assert_eq_pubkey(&signer_info, &config_data.authority)?;
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    // minters without the role draw on a session key or quota delegated by the authority
+    let delegation_info = if config_data.has_role(Role::Minter, signer_info.key) {
+        None
+    } else {
+        Some(delegation_info.ok_or(AppError::MissingRole)?)
+    };
 
-    assert_token_info(program_id, &mint_info.key, token_info)?;
-    let auth_bump = assert_mint_authority(program_id, &mint_info, mint_auth)?;
+    let amt = mint_token(
+        program_id,
+        config_info,
+        &config_data,
+        mint_info,
+        token_account,
+        token_info,
+        mint_auth,
+        blocklist_info,
+        token_program_info,
+        rent_info,
+        args,
+        false,
+    )?;
+
+    if let Some(delegation_info) = delegation_info {
+        let (session_key, _) = find_session_key(program_id, config_info.key, signer_info.key);
+        if *delegation_info.key == session_key {
+            consume_session_key(program_id, config_info, signer_info, delegation_info, true, amt)?;
+        } else {
+            consume_minter_allowance(
+                program_id,
+                config_info,
+                signer_info,
+                mint_info,
+                delegation_info,
+                amt,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Mints `args` to `token_account`, `timelocked` when run from the queue.
+/// Returns the minted amount in base units.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_token<'a>(
+    program_id: &Pubkey,
+    config_info: &AccountInfo<'a>,
+    config_data: &ConfigureData,
+    mint_info: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    token_info: &AccountInfo<'a>,
+    mint_auth: &AccountInfo<'a>,
+    blocklist_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    rent_info: &AccountInfo<'a>,
+    args: MintArgs,
+    timelocked: bool,
+) -> Result<u64, ProgramError> {
+    assert_eq_pubkey(token_program_info, &spl_token::id())?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    let auth_bump = assert_mint_authority(program_id, mint_info, mint_auth)?;
     let auth_seeds = [
         program_id.as_ref(),
         mint_info.key.as_ref(),
         "mint_auth".as_bytes(),
         &[auth_bump],
     ];
-    let mut token_data = TokenData::from_account_info(token_info)?;
-    let multiplier = (10 as u64).pow(token_data.decimals as u32) as u64;
-    let amt = args.amt.checked_mul(multiplier).ok_or(ProgramError::BorshIoError("mint amt error".into()))?;
+    let mut token_data = load_token_data(program_id, mint_info, token_info)?;
+    assert_token_namespace(config_data, &token_data)?;
+    if token_data.finalized {
+        return Err(AppError::TokenFinalized.into());
+    }
+    assert_not_paused(config_data, &token_data)?;
+    assert_not_blocklisted(program_id, config_info.key, token_account, blocklist_info)?;
+    let amt = args.unit.to_base_units(args.amt, token_data.decimals)?;
+    let is_large = config_data
+        .large_mint_threshold
+        .is_some_and(|threshold| amt > threshold);
+    if is_large && config_data.timelock_delay > 0 && !timelocked {
+        return Err(AppError::TimelockRequired.into());
+    }
+    if let Some(max_supply) = token_data.max_supply {
+        let supply = Mint::unpack(&mint_info.data.borrow())?
+            .supply
+            .checked_add(amt)
+            .ok_or(AppError::MaxSupplyExceeded)?;
+        if supply > max_supply {
+            return Err(AppError::MaxSupplyExceeded.into());
+        }
+    }
+    if let Some(rate_limit) = &token_data.rate_limit {
+        let now = match rate_limit.unit {
+            WindowUnit::Slots => now_slot(),
+            WindowUnit::Seconds => now_timestamp(),
+        };
+        let elapsed = now.saturating_sub(token_data.window_start);
+        if elapsed >= rate_limit.window {
+            token_data.window_start = now - elapsed % rate_limit.window;
+            token_data.window_minted = 0;
+        }
+        token_data.window_minted = token_data
+            .window_minted
+            .checked_add(amt)
+            .filter(|minted| *minted <= rate_limit.max_amount)
+            .ok_or(AppError::RateLimitExceeded)?;
+    }
     spl_token_mint_to(
         token_program_info,
         mint_info,
@@ -50,10 +143,11 @@ pub fn process_mint(
         mint_auth,
         &auth_seeds,
         rent_info,
-        amt
+        amt,
+        token_data.decimals,
     )?;
     
     token_data.supply += amt;
     token_data.serialize(&mut *token_info.try_borrow_mut_data()?)?;
-    Ok(())
+    Ok(amt)
 }
@@ -0,0 +1,249 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke_signed,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+use crate::{error::AppError, instruction::AppInstruction, state::*, utils::*};
+
+pub fn process_create_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: CreateMultisigArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let multisig_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let bump = assert_multisig(program_id, &args.create_key, multisig_info)?;
+
+    if !multisig_info.data_is_empty() {
+        return Err(AppError::AlreadyInitialized.into());
+    }
+    if args.signers.is_empty() || args.signers.len() > MultisigData::MAX_SIGNERS {
+        return Err(AppError::InvalidMultisigSigner.into());
+    }
+    for (i, key) in args.signers.iter().enumerate() {
+        if args.signers[..i].contains(key) {
+            return Err(AppError::InvalidMultisigSigner.into());
+        }
+    }
+    if args.threshold == 0 || args.threshold as usize > args.signers.len() {
+        return Err(AppError::InvalidThreshold.into());
+    }
+
+    create_or_allocate_account_raw(
+        *program_id,
+        multisig_info,
+        rent_info,
+        system_info,
+        signer_info,
+        MultisigData::LEN,
+        &[
+            program_id.as_ref(),
+            args.create_key.as_ref(),
+            "multisig".as_bytes(),
+            &[bump],
+        ],
+    )?;
+
+    let multisig_data = MultisigData {
+        account_type: AccountType::Multisig,
+        version: MultisigData::VERSION,
+        create_key: args.create_key,
+        signers: args.signers,
+        threshold: args.threshold,
+        proposal_count: 0,
+        bump,
+    };
+    multisig_data.serialize(&mut &mut multisig_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_create_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: CreateProposalArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let multisig_info = next_account_info(account_info_iter)?;
+    let proposal_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    assert_owned_by(multisig_info, program_id)?;
+    let mut multisig_data = MultisigData::from_account_info(multisig_info)?;
+    assert_multisig(program_id, &multisig_data.create_key, multisig_info)?;
+    if !multisig_data.is_signer(signer_info.key) {
+        return Err(AppError::InvalidMultisigSigner.into());
+    }
+
+    if args.accounts.is_empty() || args.accounts.len() > ProposalData::MAX_ACCOUNTS {
+        return Err(AppError::InvalidProposalAccounts.into());
+    }
+    // the wrapped instruction is always signed by the multisig as its first account
+    if args.accounts[0].pubkey != *multisig_info.key {
+        return Err(AppError::InvalidProposalAccounts.into());
+    }
+
+    let index = multisig_data.proposal_count;
+    let bump = assert_proposal(program_id, multisig_info.key, index, proposal_info)?;
+    create_or_allocate_account_raw(
+        *program_id,
+        proposal_info,
+        rent_info,
+        system_info,
+        signer_info,
+        ProposalData::LEN,
+        &[
+            program_id.as_ref(),
+            multisig_info.key.as_ref(),
+            &index.to_le_bytes(),
+            "proposal".as_bytes(),
+            &[bump],
+        ],
+    )?;
+
+    let proposal_data = ProposalData {
+        account_type: AccountType::Proposal,
+        version: ProposalData::VERSION,
+        multisig: *multisig_info.key,
+        index,
+        proposer: *signer_info.key,
+        action: args.action,
+        accounts: args.accounts,
+        approvals: vec![*signer_info.key],
+        executed: false,
+    };
+    proposal_data.serialize(&mut &mut proposal_info.data.borrow_mut()[..])?;
+
+    multisig_data.proposal_count = index
+        .checked_add(1)
+        .ok_or(AppError::CheckedCalculateFailed)?;
+    multisig_data.serialize(&mut &mut multisig_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_approve_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let multisig_info = next_account_info(account_info_iter)?;
+    let proposal_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_owned_by(multisig_info, program_id)?;
+    let multisig_data = MultisigData::from_account_info(multisig_info)?;
+    assert_multisig(program_id, &multisig_data.create_key, multisig_info)?;
+    if !multisig_data.is_signer(signer_info.key) {
+        return Err(AppError::InvalidMultisigSigner.into());
+    }
+
+    assert_owned_by(proposal_info, program_id)?;
+    let mut proposal_data = ProposalData::from_account_info(proposal_info)?;
+    assert_proposal(program_id, multisig_info.key, proposal_data.index, proposal_info)?;
+    if proposal_data.executed {
+        return Err(AppError::ProposalExecuted.into());
+    }
+
+    if !proposal_data.approvals.contains(signer_info.key) {
+        proposal_data.approvals.push(*signer_info.key);
+    }
+    proposal_data.serialize(&mut &mut proposal_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_execute_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let multisig_info = next_account_info(account_info_iter)?;
+    let proposal_info = next_account_info(account_info_iter)?;
+    let program_info = next_account_info(account_info_iter)?;
+    let remaining_accounts = account_info_iter.as_slice();
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(program_info, program_id)?;
+    assert_owned_by(multisig_info, program_id)?;
+    let multisig_data = MultisigData::from_account_info(multisig_info)?;
+    assert_multisig(program_id, &multisig_data.create_key, multisig_info)?;
+    if !multisig_data.is_signer(signer_info.key) {
+        return Err(AppError::InvalidMultisigSigner.into());
+    }
+
+    assert_owned_by(proposal_info, program_id)?;
+    let mut proposal_data = ProposalData::from_account_info(proposal_info)?;
+    assert_proposal(program_id, multisig_info.key, proposal_data.index, proposal_info)?;
+    if proposal_data.executed {
+        return Err(AppError::ProposalExecuted.into());
+    }
+
+    // signers removed since approving no longer count
+    let approvals = proposal_data
+        .approvals
+        .iter()
+        .filter(|key| multisig_data.is_signer(key))
+        .count();
+    if approvals < multisig_data.threshold as usize {
+        return Err(AppError::ProposalNotApproved.into());
+    }
+
+    if remaining_accounts.len() != proposal_data.accounts.len()
+        || remaining_accounts
+            .iter()
+            .zip(proposal_data.accounts.iter())
+            .any(|(info, account)| *info.key != account.pubkey)
+    {
+        return Err(AppError::InvalidProposalAccounts.into());
+    }
+
+    proposal_data.executed = true;
+    proposal_data.serialize(&mut &mut proposal_info.data.borrow_mut()[..])?;
+
+    let instruction = Instruction {
+        program_id: *program_id,
+        // signers of this transaction can sign along, e.g. to pay for accounts
+        accounts: proposal_data
+            .accounts
+            .iter()
+            .zip(remaining_accounts.iter())
+            .map(|(account, info)| AccountMeta {
+                pubkey: account.pubkey,
+                is_signer: account.pubkey == *multisig_info.key || info.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: AppInstruction::from(proposal_data.action).try_to_vec()?,
+    };
+
+    msg!("execute proposal {}", proposal_data.index);
+    let mut account_infos = remaining_accounts.to_vec();
+    account_infos.push(program_info.clone());
+    invoke_signed(
+        &instruction,
+        &account_infos,
+        &[&[
+            program_id.as_ref(),
+            multisig_data.create_key.as_ref(),
+            "multisig".as_bytes(),
+            &[multisig_data.bump],
+        ]],
+    )?;
+
+    Ok(())
+}
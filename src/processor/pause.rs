@@ -0,0 +1,43 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::{error::AppError, state::*, utils::*};
+
+pub fn process_set_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter).ok();
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    if config_data.authority != *signer_info.key
+        && !config_data.has_role(Role::Pauser, signer_info.key)
+    {
+        return Err(AppError::MissingRole.into());
+    }
+
+    match mint_info {
+        Some(mint_info) => {
+            let token_info = next_account_info(account_info_iter)?;
+            let mut token_data = load_token_data(program_id, mint_info, token_info)?;
+            assert_token_namespace(&config_data, &token_data)?;
+            token_data.paused = paused;
+            token_data.serialize(&mut &mut token_info.data.borrow_mut()[..])?;
+        }
+        None => {
+            config_data.paused = paused;
+            config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+        }
+    }
+
+    Ok(())
+}
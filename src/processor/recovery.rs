@@ -0,0 +1,173 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::{error::AppError, state::*, utils::*};
+
+/// Replaces the guardian set, `timelocked` when run from the queue
+pub fn update_guardians(
+    config_data: &mut ConfigureData,
+    args: GuardiansArgs,
+    timelocked: bool,
+) -> ProgramResult {
+    if config_data.timelock_delay > 0 && !timelocked {
+        return Err(AppError::TimelockRequired.into());
+    }
+    if args.guardians.len() > ConfigureData::MAX_GUARDIANS {
+        return Err(AppError::InvalidGuardian.into());
+    }
+    for (i, key) in args.guardians.iter().enumerate() {
+        if args.guardians[..i].contains(key) {
+            return Err(AppError::InvalidGuardian.into());
+        }
+    }
+    if args.threshold as usize > args.guardians.len() {
+        return Err(AppError::InvalidThreshold.into());
+    }
+    // recovery moves the authority, it must not be quicker than the timelock
+    if args.recovery_delay < config_data.timelock_delay {
+        return Err(AppError::InvalidRecovery.into());
+    }
+
+    config_data.guardians = args.guardians;
+    config_data.guardian_threshold = args.threshold;
+    config_data.recovery_delay = args.recovery_delay;
+    config_data.recovery = None;
+    Ok(())
+}
+
+pub fn process_set_guardians(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: GuardiansArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    update_guardians(&mut config_data, args, false)?;
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_initiate_recovery(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: RecoveryArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    if !config_data.guardians.contains(signer_info.key) {
+        return Err(AppError::InvalidGuardian.into());
+    }
+    if config_data.guardian_threshold == 0 {
+        return Err(AppError::InvalidRecovery.into());
+    }
+    let now = now_timestamp();
+    if let Some(recovery) = &config_data.recovery {
+        // a recovery left open past its window can be replaced, just not by
+        // the guardian that started it
+        let stale = now >= recovery.eta.saturating_add(Recovery::COMPLETION_WINDOW);
+        if !stale || recovery.approvals.first() == Some(signer_info.key) {
+            return Err(AppError::InvalidRecovery.into());
+        }
+        msg!("replace stale recovery to {}", recovery.new_authority);
+    }
+
+    // the timelock may have been raised since the guardians were set
+    let eta = now
+        .checked_add(config_data.recovery_delay.max(config_data.timelock_delay))
+        .ok_or(AppError::CheckedCalculateFailed)?;
+    msg!("recovery to {} executable at {}", args.new_authority, eta);
+    config_data.recovery = Some(Recovery {
+        new_authority: args.new_authority,
+        eta,
+        approvals: vec![*signer_info.key],
+    });
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_approve_recovery(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    if !config_data.guardians.contains(signer_info.key) {
+        return Err(AppError::InvalidGuardian.into());
+    }
+
+    let recovery = config_data
+        .recovery
+        .as_mut()
+        .ok_or(AppError::InvalidRecovery)?;
+    if !recovery.approvals.contains(signer_info.key) {
+        recovery.approvals.push(*signer_info.key);
+    }
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_veto_recovery(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+    if config_data.recovery.is_none() {
+        return Err(AppError::InvalidRecovery.into());
+    }
+
+    config_data.recovery = None;
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_complete_recovery(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_info = next_account_info(account_info_iter)?;
+
+    let mut config_data = load_config(program_id, config_info)?;
+    let recovery = config_data
+        .recovery
+        .take()
+        .ok_or(AppError::InvalidRecovery)?;
+    if now_timestamp() < recovery.eta {
+        return Err(AppError::RecoveryNotReady.into());
+    }
+    // guardians removed since approving no longer count
+    let approvals = recovery
+        .approvals
+        .iter()
+        .filter(|key| config_data.guardians.contains(key))
+        .count();
+    if config_data.guardian_threshold == 0 || approvals < config_data.guardian_threshold as usize {
+        return Err(AppError::RecoveryNotReady.into());
+    }
+
+    config_data.authority = recovery.new_authority;
+    config_data.pending_authority = None;
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::{error::AppError, state::*, utils::*};
+
+fn assert_can_manage(config_data: &ConfigureData, role: Role, signer_info: &AccountInfo) -> ProgramResult {
+    // admins manage the operational roles, only the authority manages admins
+    if role == Role::Admin {
+        assert_eq_pubkey(signer_info, &config_data.authority)
+    } else {
+        assert_role(config_data, Role::Admin, signer_info)
+    }
+}
+
+pub fn process_grant_role(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: RoleArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    assert_can_manage(&config_data, args.role, signer_info)?;
+
+    let members = config_data.role_members_mut(args.role);
+    if !members.contains(&args.account) {
+        if members.len() >= ConfigureData::MAX_ROLE_MEMBERS {
+            return Err(AppError::RoleMembersFull.into());
+        }
+        members.push(args.account);
+    }
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_revoke_role(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: RoleArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let mut config_data = load_config(program_id, config_info)?;
+    assert_can_manage(&config_data, args.role, signer_info)?;
+
+    let members = config_data.role_members_mut(args.role);
+    let index = members
+        .iter()
+        .position(|key| *key == args.account)
+        .ok_or(AppError::MissingRole)?;
+    members.remove(index);
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+use crate::{error::AppError, state::*, utils::*};
+
+pub fn process_create_session_key(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: SessionKeyArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let session_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    let bump = assert_session_key(program_id, config_info.key, &args.delegate, session_info)?;
+    if session_info.data_is_empty() {
+        create_or_allocate_account_raw(
+            *program_id,
+            session_info,
+            rent_info,
+            system_info,
+            signer_info,
+            SessionKey::LEN,
+            &[
+                program_id.as_ref(),
+                config_info.key.as_ref(),
+                args.delegate.as_ref(),
+                "session".as_bytes(),
+                &[bump],
+            ],
+        )?;
+    }
+    assert_owned_by(session_info, program_id)?;
+
+    let session = SessionKey {
+        account_type: AccountType::SessionKey,
+        version: SessionKey::VERSION,
+        config: *config_info.key,
+        delegate: args.delegate,
+        expires_at: args.expires_at,
+        allow_mint: args.allow_mint,
+        allow_burn: args.allow_burn,
+        max_amount: args.max_amount,
+        used_amount: 0,
+    };
+    session.serialize(&mut &mut session_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_revoke_session_key(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let session_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_eq_pubkey(signer_info, &config_data.authority)?;
+
+    assert_owned_by(session_info, program_id)?;
+    let session = SessionKey::from_account_info(session_info)?;
+    assert_session_key(program_id, config_info.key, &session.delegate, session_info)?;
+
+    close_account(session_info, signer_info)
+}
+
+/// Charges a mint (`is_mint`) or burn of `amount` base units against a session key
+pub fn consume_session_key(
+    program_id: &Pubkey,
+    config_info: &AccountInfo,
+    delegate_info: &AccountInfo,
+    session_info: &AccountInfo,
+    is_mint: bool,
+    amount: u64,
+) -> ProgramResult {
+    assert_session_key(program_id, config_info.key, delegate_info.key, session_info)?;
+    assert_owned_by(session_info, program_id)?;
+    let mut session = SessionKey::from_account_info(session_info)?;
+
+    if now_timestamp() >= session.expires_at {
+        return Err(AppError::SessionKeyExpired.into());
+    }
+    let allowed = if is_mint {
+        session.allow_mint
+    } else {
+        session.allow_burn
+    };
+    if !allowed {
+        return Err(AppError::MissingRole.into());
+    }
+
+    session.used_amount = session
+        .used_amount
+        .checked_add(amount)
+        .filter(|used| *used <= session.max_amount)
+        .ok_or(AppError::AllowanceExceeded)?;
+    session.serialize(&mut &mut session_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
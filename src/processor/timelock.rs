@@ -0,0 +1,231 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+use crate::{
+    error::AppError,
+    ferror,
+    processor::{eject_token_authorities, mint_token, update_configure, update_guardians},
+    state::*,
+    utils::*,
+};
+
+fn assert_can_schedule(config_data: &ConfigureData, action: &TimelockAction, key: &Pubkey) -> ProgramResult {
+    let allowed = match action {
+        TimelockAction::Configure(_) | TimelockAction::ProposeAuthority(_) => {
+            config_data.authority == *key
+        }
+        TimelockAction::MintToken { .. } => config_data.has_role(Role::Minter, key),
+        TimelockAction::SetRateLimit { .. }
+        | TimelockAction::SetGuardians(_)
+        | TimelockAction::EjectToken { .. } => config_data.authority == *key,
+    };
+    if !allowed {
+        return Err(AppError::MissingRole.into());
+    }
+    Ok(())
+}
+
+/// The optional trailing payer funds the timelock account in place of the
+/// signer, so a multisig can schedule through a proposal
+pub fn process_schedule_timelock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    action: TimelockAction,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let timelock_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter).unwrap_or(signer_info);
+
+    assert_signer(signer_info)?;
+    assert_signer(payer_info)?;
+    assert_eq_pubkey(rent_info, &sysvar::rent::id())?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let mut config_data = load_config(program_id, config_info)?;
+    assert_can_schedule(&config_data, &action, signer_info.key)?;
+    if let TimelockAction::SetRateLimit {
+        rate_limit: Some(rate_limit),
+        ..
+    } = &action
+    {
+        if rate_limit.window == 0 {
+            return ferror!("rate limit window must not be 0");
+        }
+    }
+
+    let index = config_data.timelock_count;
+    let bump = assert_timelock(program_id, config_info.key, index, timelock_info)?;
+    create_or_allocate_account_raw(
+        *program_id,
+        timelock_info,
+        rent_info,
+        system_info,
+        payer_info,
+        TimelockData::LEN,
+        &[
+            program_id.as_ref(),
+            config_info.key.as_ref(),
+            &index.to_le_bytes(),
+            "timelock".as_bytes(),
+            &[bump],
+        ],
+    )?;
+
+    let eta = now_timestamp()
+        .checked_add(config_data.timelock_delay)
+        .ok_or(AppError::CheckedCalculateFailed)?;
+    msg!("timelock {} executable at {}", index, eta);
+    let timelock_data = TimelockData {
+        account_type: AccountType::Timelock,
+        version: TimelockData::VERSION,
+        config: *config_info.key,
+        index,
+        proposer: *signer_info.key,
+        action,
+        eta,
+    };
+    timelock_data.serialize(&mut &mut timelock_info.data.borrow_mut()[..])?;
+
+    config_data.timelock_count = index
+        .checked_add(1)
+        .ok_or(AppError::CheckedCalculateFailed)?;
+    config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_cancel_timelock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let timelock_info = next_account_info(account_info_iter)?;
+    let proposer_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    let config_data = load_config(program_id, config_info)?;
+
+    assert_owned_by(timelock_info, program_id)?;
+    let timelock_data = TimelockData::from_account_info(timelock_info)?;
+    assert_timelock(program_id, config_info.key, timelock_data.index, timelock_info)?;
+    assert_eq_pubkey(proposer_info, &timelock_data.proposer)?;
+    if *signer_info.key != timelock_data.proposer {
+        assert_eq_pubkey(signer_info, &config_data.authority)?;
+    }
+
+    close_account(timelock_info, proposer_info)
+}
+
+pub fn process_execute_timelock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_info = next_account_info(account_info_iter)?;
+    let timelock_info = next_account_info(account_info_iter)?;
+    let proposer_info = next_account_info(account_info_iter)?;
+
+    let mut config_data = load_config(program_id, config_info)?;
+
+    assert_owned_by(timelock_info, program_id)?;
+    let timelock_data = TimelockData::from_account_info(timelock_info)?;
+    assert_timelock(program_id, config_info.key, timelock_data.index, timelock_info)?;
+    assert_eq_pubkey(proposer_info, &timelock_data.proposer)?;
+    if now_timestamp() < timelock_data.eta {
+        return Err(AppError::TimelockNotReady.into());
+    }
+    // the proposer has to still hold the rights it scheduled with
+    assert_can_schedule(&config_data, &timelock_data.action, &timelock_data.proposer)?;
+
+    match timelock_data.action {
+        TimelockAction::Configure(args) => {
+            update_configure(&mut config_data, args, true)?;
+            config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+        }
+        TimelockAction::ProposeAuthority(args) => {
+            config_data.pending_authority = Some(args.new_authority);
+            config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+        }
+        TimelockAction::MintToken {
+            mint,
+            token_account,
+            args,
+        } => {
+            let mint_info = next_account_info(account_info_iter)?;
+            let token_account_info = next_account_info(account_info_iter)?;
+            let token_info = next_account_info(account_info_iter)?;
+            let mint_auth = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+            let rent_info = next_account_info(account_info_iter)?;
+            let blocklist_info = next_account_info(account_info_iter)?;
+
+            assert_eq_pubkey(mint_info, &mint)?;
+            assert_eq_pubkey(token_account_info, &token_account)?;
+            mint_token(
+                program_id,
+                config_info,
+                &config_data,
+                mint_info,
+                token_account_info,
+                token_info,
+                mint_auth,
+                blocklist_info,
+                token_program_info,
+                rent_info,
+                args,
+                true,
+            )?;
+        }
+        TimelockAction::SetRateLimit { mint, rate_limit } => {
+            let mint_info = next_account_info(account_info_iter)?;
+            let token_info = next_account_info(account_info_iter)?;
+
+            assert_eq_pubkey(mint_info, &mint)?;
+            let mut token_data = load_token_data(program_id, mint_info, token_info)?;
+            assert_token_namespace(&config_data, &token_data)?;
+            token_data.rate_limit = rate_limit;
+            token_data.window_start = 0;
+            token_data.window_minted = 0;
+            token_data.serialize(&mut &mut token_info.data.borrow_mut()[..])?;
+        }
+        TimelockAction::SetGuardians(args) => {
+            update_guardians(&mut config_data, args, true)?;
+            config_data.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+        }
+        TimelockAction::EjectToken { mint, args } => {
+            // the creator has to sign this execution to agree to the eject
+            let creator_info = next_account_info(account_info_iter)?;
+            let mint_info = next_account_info(account_info_iter)?;
+            let token_info = next_account_info(account_info_iter)?;
+            let mint_auth = next_account_info(account_info_iter)?;
+            let metadata_info = next_account_info(account_info_iter)?;
+            let update_authority_info = next_account_info(account_info_iter)?;
+            let metadata_program_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+
+            assert_eq_pubkey(mint_info, &mint)?;
+            eject_token_authorities(
+                program_id,
+                &config_data,
+                creator_info,
+                mint_info,
+                token_info,
+                mint_auth,
+                metadata_info,
+                update_authority_info,
+                metadata_program_info,
+                token_program_info,
+                args,
+                true,
+            )?;
+        }
+    }
+
+    close_account(timelock_info, proposer_info)
+}
@@ -0,0 +1,113 @@
+use borsh::BorshSerialize;
+use mpl_token_metadata::{instructions::UpdateMetadataAccountV2InstructionArgs, types::DataV2};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{error::AppError, ferror, state::*, utils::*};
+
+pub fn process_update_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: UpdateTokenArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_info = next_account_info(account_info_iter)?;
+    let mint_auth = next_account_info(account_info_iter)?;
+    let metadata_info = next_account_info(account_info_iter)?;
+    let update_authority_info = next_account_info(account_info_iter)?;
+    let metadata_program_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_signer(signer_info)?;
+    assert_eq_pubkey(
+        metadata_program_info,
+        &mpl_token_metadata::programs::MPL_TOKEN_METADATA_ID,
+    )?;
+    assert_eq_pubkey(system_info, &solana_program::system_program::id())?;
+    let config_data = load_config(program_id, config_info)?;
+    assert_token_fields(&args.name, &args.symbol, &args.uri)?;
+
+    let mut token_data = load_token_data(program_id, mint_info, token_info)?;
+    assert_token_namespace(&config_data, &token_data)?;
+    if *signer_info.key != token_data.creator {
+        assert_role(&config_data, Role::MetadataUpdater, signer_info)?;
+    }
+    if token_data.finalized {
+        return Err(AppError::TokenFinalized.into());
+    }
+    // a PDA mint stays derived from its first symbol, renaming it would free
+    // the new symbol for a second mint
+    if args.symbol != token_data.symbol
+        && assert_token_mint(program_id, &token_data.creator, &token_data.symbol, mint_info).is_ok()
+    {
+        return ferror!("cannot change the symbol of a PDA mint");
+    }
+
+    let auth_bump = assert_mint_authority(program_id, mint_info, mint_auth)?;
+    let auth_seeds = [
+        program_id.as_ref(),
+        mint_info.key.as_ref(),
+        "mint_auth".as_bytes(),
+        &[auth_bump],
+    ];
+    update_token_metadata(
+        mint_info,
+        metadata_info,
+        update_authority_info,
+        metadata_program_info,
+        mint_auth,
+        &auth_seeds,
+        args.clone(),
+    )?;
+
+    realloc_account(token_info, signer_info, system_info, TokenData::LEN)?;
+    token_data.name = args.name;
+    token_data.symbol = args.symbol;
+    token_data.serialize(&mut &mut token_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+/// Rewrites name, symbol and uri of the metadata, keeping everything else
+pub fn update_token_metadata<'a>(
+    mint_info: &AccountInfo<'a>,
+    metadata_info: &AccountInfo<'a>,
+    update_authority_info: &AccountInfo<'a>,
+    metadata_program_info: &AccountInfo<'a>,
+    mint_auth: &AccountInfo<'a>,
+    auth_seeds: &[&[u8]],
+    args: UpdateTokenArgs,
+) -> ProgramResult {
+    let metadata = load_metadata(mint_info, metadata_info)?;
+    let data = DataV2 {
+        name: args.name,
+        symbol: args.symbol,
+        uri: args.uri,
+        seller_fee_basis_points: metadata.seller_fee_basis_points,
+        creators: metadata.creators,
+        collection: metadata.collection,
+        uses: metadata.uses,
+    };
+    mpl_update_metadata(
+        mint_info,
+        metadata_info,
+        update_authority_info,
+        metadata_program_info,
+        mint_auth,
+        auth_seeds,
+        UpdateMetadataAccountV2InstructionArgs {
+            data: Some(data),
+            new_update_authority: None,
+            primary_sale_happened: None,
+            is_mutable: None,
+        },
+    )
+}
@@ -2,42 +2,243 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
     borsh0_10::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
 
+use crate::error::AppError;
+
+/// Leading tag of every account owned by the program
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub enum AccountType {
+    #[default]
+    Uninitialized,
+    Config,
+    Token,
+    Multisig,
+    Proposal,
+    Timelock,
+    BlocklistEntry,
+    MinterAllowance,
+    SessionKey,
+    EmissionSchedule,
+}
+
+/// Checks the `account_type` and layout `version` every account starts with
+pub fn assert_account_header(a: &AccountInfo, account_type: AccountType, version: u8) -> ProgramResult {
+    let data = a.data.borrow();
+    if data.first() != Some(&(account_type as u8)) {
+        return Err(AppError::InvalidAccountType.into());
+    }
+    if data.get(1) != Some(&version) {
+        return Err(AppError::InvalidAccountVersion.into());
+    }
+    Ok(())
+}
+
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct ConfigureArgs {
     /// Contract admin
     pub authority: Pubkey,
+    /// Seconds a scheduled admin action waits before it can execute
+    pub timelock_delay: u64,
+    /// Mints above this many base units have to be scheduled
+    pub large_mint_threshold: Option<u64>,
+    /// Operator namespace the config PDA is derived from, empty for the default config
+    pub namespace: String,
+}
+
+impl ConfigureArgs {
+    pub const MAX_NAMESPACE_LEN: usize = 32;
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct ProposeAuthorityArgs {
+    /// Key that has to sign AcceptAuthority to become the admin
+    pub new_authority: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Role {
+    /// Grants and revokes the other roles
+    Admin,
+    Minter,
+    Burner,
+    Pauser,
+    MetadataUpdater,
+    /// Maintains the blocklist
+    Compliance,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct RoleArgs {
+    pub role: Role,
+    pub account: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub enum AmountUnit {
+    /// Whole tokens, scaled by the mint's decimals
+    #[default]
+    Ui,
+    /// Base units, passed to the token program as is
+    Raw,
+}
+
+impl AmountUnit {
+    pub fn to_base_units(self, amt: u64, decimals: u8) -> Result<u64, ProgramError> {
+        match self {
+            AmountUnit::Ui => 10_u64
+                .checked_pow(decimals as u32)
+                .and_then(|multiplier| amt.checked_mul(multiplier))
+                .ok_or_else(|| AppError::CheckedCalculateFailed.into()),
+            AmountUnit::Raw => Ok(amt),
+        }
+    }
 }
 
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct MintArgs {
-    /// Contract admin
+    /// Amount in `unit`
     pub amt: u64,
+    pub unit: AmountUnit,
 }
 
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct BurnArgs {
-    /// Contract admin
+    /// Amount in `unit`
     pub amt: u64,
+    pub unit: AmountUnit,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct GuardiansArgs {
+    pub guardians: Vec<Pubkey>,
+    /// Guardian approvals a recovery needs, 0 disables recovery
+    pub threshold: u8,
+    /// Seconds the authority has to veto a recovery, at least the timelock delay
+    pub recovery_delay: u64,
+}
+
+impl GuardiansArgs {
+    pub const LEN: usize = 4 + 32 * ConfigureData::MAX_GUARDIANS + 1 + 8;
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct RecoveryArgs {
+    pub new_authority: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct Recovery {
+    pub new_authority: Pubkey,
+    /// Unix timestamp from which the recovery can complete
+    pub eta: u64,
+    pub approvals: Vec<Pubkey>,
+}
+
+impl Recovery {
+    /// Seconds after `eta` before other guardians can replace the recovery
+    pub const COMPLETION_WINDOW: u64 = 24 * 60 * 60;
+    pub const LEN: usize = 32 + 8 + 4 + 32 * ConfigureData::MAX_GUARDIANS;
 }
 
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct ConfigureData {
+    pub account_type: AccountType,
+    pub version: u8,
     /// Contract admin
     pub authority: Pubkey,
+    /// Proposed admin, waiting to accept
+    pub pending_authority: Option<Pubkey>,
+    pub admins: Vec<Pubkey>,
+    pub minters: Vec<Pubkey>,
+    pub burners: Vec<Pubkey>,
+    pub pausers: Vec<Pubkey>,
+    pub metadata_updaters: Vec<Pubkey>,
+    pub compliance: Vec<Pubkey>,
+    /// Seconds a scheduled admin action waits before it can execute
+    pub timelock_delay: u64,
+    /// Mints above this many base units have to be scheduled
+    pub large_mint_threshold: Option<u64>,
+    /// Index of the next timelock operation
+    pub timelock_count: u64,
+    /// Stops mint and burn for every token
+    pub paused: bool,
+    /// Operator namespace the config PDA is derived from
+    pub namespace: String,
+    /// Keys that can recover a lost authority
+    pub guardians: Vec<Pubkey>,
+    pub guardian_threshold: u8,
+    pub recovery_delay: u64,
+    /// Recovery in progress
+    pub recovery: Option<Recovery>,
 }
 
 impl ConfigureData {
-    pub const LEN: usize = 32;
+    pub const MAX_ROLE_MEMBERS: usize = 8;
+    pub const MAX_GUARDIANS: usize = 8;
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1
+        + 1
+        + 32
+        + 33
+        + 6 * (4 + 32 * Self::MAX_ROLE_MEMBERS)
+        + 8
+        + 9
+        + 8
+        + 1
+        + 4
+        + ConfigureArgs::MAX_NAMESPACE_LEN
+        + 4
+        + 32 * Self::MAX_GUARDIANS
+        + 1
+        + 8
+        + 1
+        + Recovery::LEN;
+
+    pub fn role_members(&self, role: Role) -> &Vec<Pubkey> {
+        match role {
+            Role::Admin => &self.admins,
+            Role::Minter => &self.minters,
+            Role::Burner => &self.burners,
+            Role::Pauser => &self.pausers,
+            Role::MetadataUpdater => &self.metadata_updaters,
+            Role::Compliance => &self.compliance,
+        }
+    }
+
+    pub fn role_members_mut(&mut self, role: Role) -> &mut Vec<Pubkey> {
+        match role {
+            Role::Admin => &mut self.admins,
+            Role::Minter => &mut self.minters,
+            Role::Burner => &mut self.burners,
+            Role::Pauser => &mut self.pausers,
+            Role::MetadataUpdater => &mut self.metadata_updaters,
+            Role::Compliance => &mut self.compliance,
+        }
+    }
+
+    /// The authority is implicitly an admin, every other role has to be granted
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        (role == Role::Admin && self.authority == *key) || self.role_members(role).contains(key)
+    }
 
     pub fn from_account_info(a: &AccountInfo) -> Result<ConfigureData, ProgramError> {
+        assert_account_header(a, AccountType::Config, Self::VERSION)?;
         if a.data_len() != Self::LEN {
             return Err(ProgramError::InvalidAccountData);
         }
@@ -52,29 +253,553 @@ pub struct CreateTokenArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
-    pub decimals: u8
+    pub decimals: u8,
+    /// Derive the mint from `[program_id, creator, symbol, "mint"]`
+    /// instead of using a keypair mint
+    pub pda_mint: bool,
+    /// Hard cap on the mint supply in base units, fixed at creation
+    pub max_supply: Option<u64>,
 }
 
 
 #[repr(C)]
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct UpdateTokenArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct AdoptTokenArgs {
+    pub name: String,
+    pub symbol: String,
+    /// Only used when the mint has no metadata yet
+    pub uri: String,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct EjectTokenArgs {
+    /// Receives the mint, freeze and metadata update authorities
+    pub new_owner: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub enum WindowUnit {
+    #[default]
+    Slots,
+    Seconds,
+}
+
+/// Caps how much of a token can be minted per window
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct RateLimit {
+    /// Base units that can be minted per window
+    pub max_amount: u64,
+    /// Window length in `unit`
+    pub window: u64,
+    pub unit: WindowUnit,
+}
+
+impl RateLimit {
+    pub const LEN: usize = 8 + 8 + 1;
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct  TokenData {
+    pub account_type: AccountType,
+    pub version: u8,
     pub name: String,
     pub symbol: String,
     pub decimals: u8,
     pub mint: Pubkey,
     pub creator: Pubkey,
     pub supply: u64,
+    /// Stops mint and burn for this token
+    pub paused: bool,
+    /// Namespace of the config that controls this token
+    pub namespace: String,
+    /// Mint and freeze authorities renounced, supply is fixed
+    pub finalized: bool,
+    /// Hard cap on the mint supply in base units
+    pub max_supply: Option<u64>,
+    pub rate_limit: Option<RateLimit>,
+    /// Slot or timestamp the current rate limit window started at
+    pub window_start: u64,
+    /// Base units minted in the current rate limit window
+    pub window_minted: u64,
 }
 
 impl TokenData {
+    /// Metaplex limits
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_SYMBOL_LEN: usize = 10;
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub const VERSION: u8 = 1;
     // pub const LEN: usize = 8 * 9 + 4 + 32 * 3 + 32 * 100 + 4;
-    pub const LEN: usize = 32 + 10 + 1 + 32 + 32 + 8;
+    pub const LEN: usize = 1
+        + 1
+        + 4
+        + Self::MAX_NAME_LEN
+        + 4
+        + Self::MAX_SYMBOL_LEN
+        + 1
+        + 32
+        + 32
+        + 8
+        + 1
+        + 4
+        + ConfigureArgs::MAX_NAMESPACE_LEN
+        + 1
+        + 9
+        + 1
+        + RateLimit::LEN
+        + 8
+        + 8;
 
+    /// Accounts created before a field was added are smaller than `LEN`
+    /// until they are grown with `realloc_account`
     pub fn from_account_info(a: &AccountInfo) -> Result<TokenData, ProgramError> {
+        assert_account_header(a, AccountType::Token, Self::VERSION)?;
+        if a.data_is_empty() || a.data_len() > Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        try_from_slice_unchecked(&a.data.borrow_mut()).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CreateMultisigArgs {
+    /// Arbitrary key the multisig PDA is derived from
+    pub create_key: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct MultisigData {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub create_key: Pubkey,
+    pub signers: Vec<Pubkey>,
+    /// Approvals required to execute a proposal
+    pub threshold: u8,
+    /// Index of the next proposal
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+impl MultisigData {
+    pub const MAX_SIGNERS: usize = 10;
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 1 + 32 + 4 + 32 * Self::MAX_SIGNERS + 1 + 8 + 1;
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<MultisigData, ProgramError> {
+        assert_account_header(a, AccountType::Multisig, Self::VERSION)?;
         if a.data_len() != Self::LEN {
             return Err(ProgramError::InvalidAccountData);
         }
         try_from_slice_unchecked(&a.data.borrow_mut()).map_err(|_| ProgramError::InvalidAccountData)
     }
+
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
+
+/// Instructions a multisig proposal can execute as the multisig
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum ProposalAction {
+    Configure(ConfigureArgs),
+    MintToken(MintArgs),
+    BurnToken(BurnArgs),
+    ProposeAuthority(ProposeAuthorityArgs),
+    AcceptAuthority,
+    GrantRole(RoleArgs),
+    RevokeRole(RoleArgs),
+    SetGuardians(GuardiansArgs),
+    ScheduleTimelock(TimelockAction),
+    CancelAuthority,
+    CancelTimelock,
+    VetoRecovery,
+    FreezeAccount,
+    ThawAccount,
+}
+
+impl ProposalAction {
+    // ScheduleTimelock is the largest variant
+    pub const LEN: usize = 1 + TimelockAction::LEN;
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_writable: bool,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CreateProposalArgs {
+    pub action: ProposalAction,
+    /// Accounts of the wrapped instruction, in order
+    pub accounts: Vec<ProposalAccount>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ProposalData {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub accounts: Vec<ProposalAccount>,
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+}
+
+impl ProposalData {
+    pub const MAX_ACCOUNTS: usize = 16;
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1
+        + 1
+        + 32
+        + 8
+        + 32
+        + ProposalAction::LEN
+        + 4
+        + 33 * Self::MAX_ACCOUNTS
+        + 4
+        + 32 * MultisigData::MAX_SIGNERS
+        + 1;
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<ProposalData, ProgramError> {
+        assert_account_header(a, AccountType::Proposal, Self::VERSION)?;
+        if a.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        try_from_slice_unchecked(&a.data.borrow_mut()).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Admin actions that can be queued behind the config timelock
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum TimelockAction {
+    Configure(ConfigureArgs),
+    ProposeAuthority(ProposeAuthorityArgs),
+    MintToken {
+        mint: Pubkey,
+        token_account: Pubkey,
+        args: MintArgs,
+    },
+    SetRateLimit {
+        mint: Pubkey,
+        rate_limit: Option<RateLimit>,
+    },
+    SetGuardians(GuardiansArgs),
+    EjectToken {
+        mint: Pubkey,
+        args: EjectTokenArgs,
+    },
+}
+
+impl TimelockAction {
+    // SetGuardians is the largest variant
+    pub const LEN: usize = 1 + GuardiansArgs::LEN;
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct TimelockData {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub config: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: TimelockAction,
+    /// Unix timestamp from which the action can execute
+    pub eta: u64,
+}
+
+impl TimelockData {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 1 + 32 + 8 + 32 + TimelockAction::LEN + 8;
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<TimelockData, ProgramError> {
+        assert_account_header(a, AccountType::Timelock, Self::VERSION)?;
+        if a.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        try_from_slice_unchecked(&a.data.borrow_mut()).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct BlocklistArgs {
+    pub wallet: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct BlocklistEntry {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub config: Pubkey,
+    /// Blocked wallet, matched against token account owners
+    pub wallet: Pubkey,
+}
+
+impl BlocklistEntry {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 1 + 32 + 32;
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<BlocklistEntry, ProgramError> {
+        assert_account_header(a, AccountType::BlocklistEntry, Self::VERSION)?;
+        if a.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        try_from_slice_unchecked(&a.data.borrow_mut()).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct MinterAllowanceArgs {
+    pub minter: Pubkey,
+    /// Restricts the quota to one mint, `None` covers every token of the config
+    pub mint: Option<Pubkey>,
+    /// Base units the minter may mint per period
+    pub amount: u64,
+    /// Seconds after which the quota refills, `None` for a one-off quota
+    pub reset_period: Option<u64>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct MinterAllowance {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    pub mint: Option<Pubkey>,
+    /// Quota per period, in base units
+    pub amount: u64,
+    /// Quota left in the current period, in base units
+    pub remaining: u64,
+    pub reset_period: Option<u64>,
+    /// Unix timestamp the current period started at
+    pub period_start: u64,
+}
+
+impl MinterAllowance {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 33 + 8 + 8 + 9 + 8;
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<MinterAllowance, ProgramError> {
+        assert_account_header(a, AccountType::MinterAllowance, Self::VERSION)?;
+        if a.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        try_from_slice_unchecked(&a.data.borrow_mut()).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct SessionKeyArgs {
+    pub delegate: Pubkey,
+    /// Unix timestamp the session stops working at
+    pub expires_at: u64,
+    pub allow_mint: bool,
+    pub allow_burn: bool,
+    /// Base units the session may mint and burn in total
+    pub max_amount: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct SessionKey {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub config: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at: u64,
+    pub allow_mint: bool,
+    pub allow_burn: bool,
+    pub max_amount: u64,
+    /// Base units minted and burned so far
+    pub used_amount: u64,
+}
+
+impl SessionKey {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 8 + 1 + 1 + 8 + 8;
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<SessionKey, ProgramError> {
+        assert_account_header(a, AccountType::SessionKey, Self::VERSION)?;
+        if a.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        try_from_slice_unchecked(&a.data.borrow_mut()).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Amount minted for each period of an emission schedule, in whole tokens
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum EmissionCurve {
+    Fixed {
+        amount: u64,
+    },
+    /// Halves `initial_amount` every `halving_periods` periods
+    Halving {
+        initial_amount: u64,
+        halving_periods: u64,
+    },
+    /// Lowers `initial_amount` by `decrement` every period until it reaches 0
+    Linear {
+        initial_amount: u64,
+        decrement: u64,
+    },
+}
+
+impl EmissionCurve {
+    pub const LEN: usize = 1 + 8 + 8;
+
+    /// Amount vested in period `index`, counted from 0
+    pub fn amount(&self, index: u64) -> u64 {
+        match *self {
+            EmissionCurve::Fixed { amount } => amount,
+            EmissionCurve::Halving {
+                initial_amount,
+                halving_periods,
+            } => initial_amount
+                .checked_shr((index / halving_periods).min(u32::MAX as u64) as u32)
+                .unwrap_or(0),
+            EmissionCurve::Linear {
+                initial_amount,
+                decrement,
+            } => initial_amount.saturating_sub(decrement.saturating_mul(index)),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct EmissionScheduleArgs {
+    pub curve: EmissionCurve,
+    /// Token account every crank mints to
+    pub recipient: Pubkey,
+    /// Unix timestamp the first period starts at
+    pub start: u64,
+    /// Period length in seconds
+    pub period: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct EmissionSchedule {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub curve: EmissionCurve,
+    pub start: u64,
+    pub period: u64,
+    /// Periods already minted
+    pub emitted_periods: u64,
+}
+
+impl EmissionSchedule {
+    /// Bounds the work of one crank, later cranks catch up on the rest
+    pub const MAX_PERIODS_PER_CRANK: u64 = 64;
+
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 32 + EmissionCurve::LEN + 8 + 8 + 8;
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<EmissionSchedule, ProgramError> {
+        assert_account_header(a, AccountType::EmissionSchedule, Self::VERSION)?;
+        if a.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        try_from_slice_unchecked(&a.data.borrow_mut()).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_base_units_scales_ui_amounts() {
+        assert_eq!(AmountUnit::Ui.to_base_units(5, 0), Ok(5));
+        assert_eq!(AmountUnit::Ui.to_base_units(5, 9), Ok(5_000_000_000));
+        assert_eq!(AmountUnit::Ui.to_base_units(1, 19), Ok(10_000_000_000_000_000_000));
+    }
+
+    #[test]
+    fn to_base_units_passes_raw_amounts_through() {
+        assert_eq!(AmountUnit::Raw.to_base_units(5, 9), Ok(5));
+        assert_eq!(AmountUnit::Raw.to_base_units(u64::MAX, 255), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn to_base_units_rejects_overflow() {
+        let overflow = Err(AppError::CheckedCalculateFailed.into());
+        // 10^20 does not fit in a u64
+        assert_eq!(AmountUnit::Ui.to_base_units(1, 20), overflow);
+        assert_eq!(AmountUnit::Ui.to_base_units(0, 20), overflow);
+        assert_eq!(AmountUnit::Ui.to_base_units(2, 19), overflow);
+        assert_eq!(AmountUnit::Ui.to_base_units(u64::MAX, 1), overflow);
+    }
+
+    #[test]
+    fn halving_curve_halves_every_period_group() {
+        let curve = EmissionCurve::Halving {
+            initial_amount: 1_000,
+            halving_periods: 2,
+        };
+        assert_eq!(curve.amount(0), 1_000);
+        assert_eq!(curve.amount(1), 1_000);
+        assert_eq!(curve.amount(2), 500);
+        assert_eq!(curve.amount(5), 250);
+    }
+
+    #[test]
+    fn halving_curve_reaches_zero_past_64_halvings() {
+        let curve = EmissionCurve::Halving {
+            initial_amount: u64::MAX,
+            halving_periods: 1,
+        };
+        assert_eq!(curve.amount(63), 1);
+        assert_eq!(curve.amount(64), 0);
+        assert_eq!(curve.amount(1_000), 0);
+        assert_eq!(curve.amount(u64::MAX), 0);
+    }
+
+    #[test]
+    fn linear_curve_stops_at_zero() {
+        let curve = EmissionCurve::Linear {
+            initial_amount: 100,
+            decrement: 30,
+        };
+        assert_eq!(curve.amount(0), 100);
+        assert_eq!(curve.amount(3), 10);
+        assert_eq!(curve.amount(4), 0);
+        assert_eq!(curve.amount(u64::MAX), 0);
+    }
 }
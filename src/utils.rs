@@ -1,6 +1,15 @@
 use borsh::BorshDeserialize;
+use mpl_token_metadata::{
+    accounts::Metadata,
+    instructions::{
+        CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs, UpdateMetadataAccountV2,
+        UpdateMetadataAccountV2InstructionArgs,
+    },
+    types::DataV2,
+};
 use solana_program::{
     account_info::AccountInfo,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
     entrypoint::ProgramResult,
     msg,
     program::{invoke, invoke_signed},
@@ -12,12 +21,19 @@ use solana_program::{
 };
 use std::io::Error;
 
-use crate::error::AppError;
+use crate::{
+    error::AppError,
+    state::{ConfigureData, Role, TokenData},
+};
 
 pub fn now_timestamp() -> u64 {
     Clock::get().unwrap().unix_timestamp as u64
 }
 
+pub fn now_slot() -> u64 {
+    Clock::get().unwrap().slot
+}
+
 pub fn assert_eq_pubkey(account_info: &AccountInfo, account: &Pubkey) -> ProgramResult {
     if account_info.key != account {
         Err(AppError::InvalidEqPubkey.into())
@@ -26,6 +42,22 @@ pub fn assert_eq_pubkey(account_info: &AccountInfo, account: &Pubkey) -> Program
     }
 }
 
+pub fn assert_role(config_data: &ConfigureData, role: Role, account_info: &AccountInfo) -> ProgramResult {
+    if !config_data.has_role(role, account_info.key) {
+        Err(AppError::MissingRole.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_not_paused(config_data: &ConfigureData, token_data: &TokenData) -> ProgramResult {
+    if config_data.paused || token_data.paused {
+        Err(AppError::Paused.into())
+    } else {
+        Ok(())
+    }
+}
+
 pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
     if account.owner != owner {
         Err(AppError::InvalidOwner.into())
@@ -39,26 +71,165 @@ pub fn assert_derivation(
     account: &AccountInfo,
     path: &[&[u8]],
 ) -> Result<u8, ProgramError> {
-    let (key, bump) = Pubkey::find_program_address(&path, program_id);
+    let (key, bump) = Pubkey::find_program_address(path, program_id);
     if key != *account.key {
         return Err(AppError::InvalidDerivedKey.into());
     }
     Ok(bump)
 }
 
-pub fn assert_config(program_id: &Pubkey, account: &AccountInfo) -> Result<u8, ProgramError> {
-    let path = &[program_id.as_ref(), "config".as_bytes()];
-    assert_derivation(&program_id, &account, path)
+pub fn assert_config(program_id: &Pubkey, namespace: &str, account: &AccountInfo) -> Result<u8, ProgramError> {
+    let path = &[program_id.as_ref(), "config".as_bytes(), namespace.as_bytes()];
+    assert_derivation(program_id, account, path)
+}
+
+/// Loads a config and checks it sits at the address of the namespace it records
+pub fn load_config(program_id: &Pubkey, account: &AccountInfo) -> Result<ConfigureData, ProgramError> {
+    assert_owned_by(account, program_id)?;
+    let config_data = ConfigureData::from_account_info(account)?;
+    assert_config(program_id, &config_data.namespace, account)?;
+    Ok(config_data)
+}
+
+pub fn assert_token_namespace(config_data: &ConfigureData, token_data: &TokenData) -> ProgramResult {
+    if config_data.namespace != token_data.namespace {
+        Err(AppError::NamespaceMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_token_mint(program_id: &Pubkey, creator: &Pubkey, symbol: &str, account: &AccountInfo) -> Result<u8, ProgramError> {
+    let path = &[program_id.as_ref(), creator.as_ref(), symbol.as_bytes(), "mint".as_bytes()];
+    assert_derivation(program_id, account, path)
 }
 
 pub fn assert_token_info(program_id: &Pubkey,new_mint: &Pubkey, account: &AccountInfo) -> Result<u8, ProgramError> {
     let path = &[program_id.as_ref(), new_mint.as_ref(), "token_info".as_bytes()];
-    assert_derivation(&program_id, &account, path)
+    assert_derivation(program_id, account, path)
+}
+
+pub fn assert_token_fields(name: &str, symbol: &str, uri: &str) -> ProgramResult {
+    if name.len() > TokenData::MAX_NAME_LEN {
+        return Err(AppError::NameTooLong.into());
+    }
+    if symbol.len() > TokenData::MAX_SYMBOL_LEN {
+        return Err(AppError::SymbolTooLong.into());
+    }
+    if uri.len() > TokenData::MAX_URI_LEN {
+        return Err(AppError::UriTooLong.into());
+    }
+    Ok(())
+}
+
+/// Loads the `token_info` of `mint` and checks it still agrees with the mint
+pub fn load_token_data(program_id: &Pubkey, mint: &AccountInfo, token_info: &AccountInfo) -> Result<TokenData, ProgramError> {
+    assert_token_info(program_id, mint.key, token_info)?;
+    let token_data = TokenData::from_account_info(token_info)?;
+    assert_token_mint_matches(&token_data, mint)?;
+    Ok(token_data)
+}
+
+pub fn assert_token_mint_matches(token_data: &TokenData, mint: &AccountInfo) -> ProgramResult {
+    assert_owned_by(mint, &spl_token::id())?;
+    let mint_data = spl_token::state::Mint::unpack(&mint.data.borrow())?;
+    if token_data.mint != *mint.key || token_data.decimals != mint_data.decimals {
+        return Err(AppError::TokenMintMismatch.into());
+    }
+    Ok(())
 }
 
 pub fn assert_user_info(program_id: &Pubkey,user: &Pubkey, account: &AccountInfo) -> Result<u8, ProgramError> {
     let path = &[program_id.as_ref(), user.as_ref(), "user_info".as_bytes()];
-    assert_derivation(&program_id, &account, path)
+    assert_derivation(program_id, account, path)
+}
+
+pub fn assert_multisig(program_id: &Pubkey, create_key: &Pubkey, account: &AccountInfo) -> Result<u8, ProgramError> {
+    let path = &[program_id.as_ref(), create_key.as_ref(), "multisig".as_bytes()];
+    assert_derivation(program_id, account, path)
+}
+
+pub fn assert_proposal(program_id: &Pubkey, multisig: &Pubkey, index: u64, account: &AccountInfo) -> Result<u8, ProgramError> {
+    let path = &[program_id.as_ref(), multisig.as_ref(), &index.to_le_bytes(), "proposal".as_bytes()];
+    assert_derivation(program_id, account, path)
+}
+
+pub fn assert_timelock(program_id: &Pubkey, config: &Pubkey, index: u64, account: &AccountInfo) -> Result<u8, ProgramError> {
+    let path = &[program_id.as_ref(), config.as_ref(), &index.to_le_bytes(), "timelock".as_bytes()];
+    assert_derivation(program_id, account, path)
+}
+
+pub fn assert_blocklist(program_id: &Pubkey, config: &Pubkey, wallet: &Pubkey, account: &AccountInfo) -> Result<u8, ProgramError> {
+    let path = &[program_id.as_ref(), config.as_ref(), wallet.as_ref(), "blocklist".as_bytes()];
+    assert_derivation(program_id, account, path)
+}
+
+/// Fails when `blocklist_info` is a live blocklist entry for the token account owner
+pub fn assert_not_blocklisted(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    token_account: &AccountInfo,
+    blocklist_info: &AccountInfo,
+) -> ProgramResult {
+    let owner = spl_token::state::Account::unpack(&token_account.data.borrow())?.owner;
+    assert_blocklist(program_id, config, &owner, blocklist_info)?;
+    if blocklist_info.owner == program_id && !blocklist_info.data_is_empty() {
+        return Err(AppError::Blocklisted.into());
+    }
+    Ok(())
+}
+
+/// `mint` is `None` for an allowance that covers every token of the config
+pub fn assert_minter_allowance(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    minter: &Pubkey,
+    mint: Option<&Pubkey>,
+    account: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let mint = mint.copied().unwrap_or_default();
+    let path = &[program_id.as_ref(), config.as_ref(), minter.as_ref(), mint.as_ref(), "allowance".as_bytes()];
+    assert_derivation(program_id, account, path)
+}
+
+pub fn find_session_key(program_id: &Pubkey, config: &Pubkey, delegate: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[program_id.as_ref(), config.as_ref(), delegate.as_ref(), "session".as_bytes()],
+        program_id,
+    )
+}
+
+pub fn assert_session_key(program_id: &Pubkey, config: &Pubkey, delegate: &Pubkey, account: &AccountInfo) -> Result<u8, ProgramError> {
+    let path = &[program_id.as_ref(), config.as_ref(), delegate.as_ref(), "session".as_bytes()];
+    assert_derivation(program_id, account, path)
+}
+
+pub fn assert_emission_schedule(program_id: &Pubkey, mint: &Pubkey, account: &AccountInfo) -> Result<u8, ProgramError> {
+    let path = &[program_id.as_ref(), mint.as_ref(), "emission".as_bytes()];
+    assert_derivation(program_id, account, path)
+}
+
+pub fn assert_upgrade_authority(
+    program_id: &Pubkey,
+    program_data_info: &AccountInfo,
+    authority_info: &AccountInfo,
+) -> ProgramResult {
+    let (program_data, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+    assert_eq_pubkey(program_data_info, &program_data)?;
+    assert_owned_by(program_data_info, &bpf_loader_upgradeable::id())?;
+
+    let data = program_data_info.try_borrow_data()?;
+    let metadata = data
+        .get(..UpgradeableLoaderState::size_of_programdata_metadata())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    match bincode::deserialize(metadata) {
+        Ok(UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address: Some(upgrade_authority),
+            ..
+        }) if upgrade_authority == *authority_info.key => Ok(()),
+        _ => Err(AppError::InvalidUpgradeAuthority.into()),
+    }
 }
 
 pub fn assert_signer(account_info: &AccountInfo) -> ProgramResult {
@@ -79,7 +250,7 @@ pub fn assert_mint_authority(
         token.key.as_ref(),
         "mint_auth".as_bytes(),
     ];
-    assert_derivation(&program_id, &authority_info, path)
+    assert_derivation(program_id, authority_info, path)
 }
 
 #[inline(always)]
@@ -101,7 +272,7 @@ pub fn create_or_allocate_account_raw<'a>(
     if required_lamports > 0 {
         msg!("Transfer {} lamports to the new account", required_lamports);
         invoke(
-            &system_instruction::transfer(&payer_info.key, new_account_info.key, required_lamports),
+            &system_instruction::transfer(payer_info.key, new_account_info.key, required_lamports),
             &[
                 payer_info.clone(),
                 new_account_info.clone(),
@@ -114,20 +285,51 @@ pub fn create_or_allocate_account_raw<'a>(
     invoke_signed(
         &system_instruction::allocate(new_account_info.key, size.try_into().unwrap()),
         &[new_account_info.clone(), system_program_info.clone()],
-        &[&signer_seeds],
+        &[signer_seeds],
     )?;
 
     msg!("Assign the account to the owning program");
     invoke_signed(
         &system_instruction::assign(new_account_info.key, &program_id),
         &[new_account_info.clone(), system_program_info.clone()],
-        &[&signer_seeds],
+        &[signer_seeds],
     )?;
     msg!("Completed assignation!");
 
     Ok(())
 }
 
+/// Grows `account` to `len`, topping up rent from `payer_info`
+pub fn realloc_account<'a>(
+    account: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    system_info: &AccountInfo<'a>,
+    len: usize,
+) -> ProgramResult {
+    if account.data_len() >= len {
+        return Ok(());
+    }
+    let required_lamports = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(account.lamports());
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, account.key, required_lamports),
+            &[payer_info.clone(), account.clone(), system_info.clone()],
+        )?;
+    }
+    account.realloc(len, false)
+}
+
+pub fn close_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    let lamports = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(AppError::CheckedCalculateFailed)?;
+    **destination.lamports.borrow_mut() = lamports;
+    **account.lamports.borrow_mut() = 0;
+    account.realloc(0, false)
+}
 
 pub fn try_from_slice_unchecked<T: BorshDeserialize>(data: &[u8]) -> Result<T, Error> {
     let mut data_mut = data;
@@ -135,7 +337,17 @@ pub fn try_from_slice_unchecked<T: BorshDeserialize>(data: &[u8]) -> Result<T, E
     Ok(result)
 }
 
+/// Empty seeds sign for nothing instead of for the PDA of no seeds
+fn signer_seeds<'a, 'b>(seeds: &'a [&'b [u8]]) -> Vec<&'a [&'b [u8]]> {
+    if seeds.is_empty() {
+        vec![]
+    } else {
+        vec![seeds]
+    }
+}
+
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 pub fn spl_token_create_mint<'a>(
     token_program: &AccountInfo<'a>,
     payer_info: &AccountInfo<'a>,
@@ -144,24 +356,39 @@ pub fn spl_token_create_mint<'a>(
     create_account_seeds: &[&[u8]], // when account is not a pda, is null
     initialize_mint_seeds: &[&[u8]], // when account is not a pda, is null
     rent_info: &AccountInfo<'a>,
+    system_info: &AccountInfo<'a>,
     decimals: u8,
 ) -> Result<(), ProgramError> {
-    let size = spl_token::state::Account::LEN;
-    let rent = &Rent::from_account_info(&rent_info)?;
-    let required_lamports = rent.minimum_balance(size);
+    let size = spl_token::state::Mint::LEN;
 
     msg!("spl_token_create_token create");
-    invoke_signed(
-        &system_instruction::create_account(
-            payer_info.key,
-            new_mint.key,
-            required_lamports,
-            size as u64,
-            token_program.key,
-        ),
-        &[payer_info.clone(), new_mint.clone()],
-        &[create_account_seeds],
-    )?;
+    if create_account_seeds.is_empty() {
+        let rent = &Rent::from_account_info(rent_info)?;
+        let required_lamports = rent.minimum_balance(size);
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                new_mint.key,
+                required_lamports,
+                size as u64,
+                token_program.key,
+            ),
+            &[payer_info.clone(), new_mint.clone()],
+            &signer_seeds(create_account_seeds),
+        )?;
+    } else {
+        // a PDA mint address is predictable and may already hold lamports,
+        // which makes create_account fail
+        create_or_allocate_account_raw(
+            *token_program.key,
+            new_mint,
+            rent_info,
+            system_info,
+            payer_info,
+            size,
+            create_account_seeds,
+        )?;
+    }
 
     msg!("spl_token_initialize mint");
     invoke_signed(
@@ -178,12 +405,13 @@ pub fn spl_token_create_mint<'a>(
             authority.clone(),
             rent_info.clone(),
         ],
-        &[initialize_mint_seeds],
+        &signer_seeds(initialize_mint_seeds),
     )?;
     Ok(())
 }
 
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 pub fn spl_token_mint_to<'a>(
     token_program: &AccountInfo<'a>,
     new_mint: &AccountInfo<'a>,
@@ -192,16 +420,18 @@ pub fn spl_token_mint_to<'a>(
     mint_to_seeds: &[&[u8]],
     rent_info: &AccountInfo<'a>,
     amt: u64,
+    decimals: u8,
 ) -> Result<(), ProgramError> {
     msg!("spl_token_mint_to mint");
     invoke_signed(
-        &spl_token::instruction::mint_to(
+        &spl_token::instruction::mint_to_checked(
             token_program.key,
             new_mint.key,
             token_account.key,
             authority.key,
             &[authority.key],
             amt,
+            decimals,
         )?,
         &[
             token_program.clone(),
@@ -225,15 +455,17 @@ pub fn spl_token_burn<'a>(
     authority: &AccountInfo<'a>,
     rent_info: &AccountInfo<'a>,
     amt: u64,
+    decimals: u8,
 ) -> Result<(), ProgramError> {
     invoke(
-        &spl_token::instruction::burn(
+        &spl_token::instruction::burn_checked(
             token_program.key,
             token_account.key,
             new_mint.key,
             authority.key,
             &[authority.key],
             amt,
+            decimals,
         )?,
         &[
             token_program.clone(),
@@ -246,4 +478,183 @@ pub fn spl_token_burn<'a>(
 
     msg!("spl_token_burn success");
     Ok(())
-}
\ No newline at end of file
+}
+#[inline(always)]
+pub fn spl_token_freeze_account<'a>(
+    token_program: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    freeze_seeds: &[&[u8]],
+) -> Result<(), ProgramError> {
+    invoke_signed(
+        &spl_token::instruction::freeze_account(
+            token_program.key,
+            token_account.key,
+            mint.key,
+            authority.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            token_account.clone(),
+            mint.clone(),
+            authority.clone(),
+        ],
+        &[freeze_seeds],
+    )?;
+
+    msg!("spl_token_freeze_account success");
+    Ok(())
+}
+
+#[inline(always)]
+pub fn spl_token_thaw_account<'a>(
+    token_program: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    thaw_seeds: &[&[u8]],
+) -> Result<(), ProgramError> {
+    invoke_signed(
+        &spl_token::instruction::thaw_account(
+            token_program.key,
+            token_account.key,
+            mint.key,
+            authority.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            token_account.clone(),
+            mint.clone(),
+            authority.clone(),
+        ],
+        &[thaw_seeds],
+    )?;
+
+    msg!("spl_token_thaw_account success");
+    Ok(())
+}
+
+/// Moves `authority_type` on `mint` from the `authority` PDA to `new_authority`
+#[inline(always)]
+pub fn spl_token_set_authority<'a>(
+    token_program: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    new_authority: Option<&Pubkey>,
+    authority_type: spl_token::instruction::AuthorityType,
+    authority_seeds: &[&[u8]],
+) -> Result<(), ProgramError> {
+    invoke_signed(
+        &spl_token::instruction::set_authority(
+            token_program.key,
+            mint.key,
+            new_authority,
+            authority_type,
+            authority.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            mint.clone(),
+            authority.clone(),
+        ],
+        &[authority_seeds],
+    )?;
+
+    msg!("spl_token_set_authority success");
+    Ok(())
+}
+
+/// Creates mutable metadata for `mint` with the `mint_auth` PDA as mint
+/// and update authority
+#[allow(clippy::too_many_arguments)]
+pub fn mpl_create_metadata<'a>(
+    mint: &AccountInfo<'a>,
+    metadata_info: &AccountInfo<'a>,
+    mint_auth: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    metadata_program_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    system_info: &AccountInfo<'a>,
+    rent_info: &AccountInfo<'a>,
+    auth_seeds: &[&[u8]],
+    data: DataV2,
+) -> ProgramResult {
+    let cmv3 = CreateMetadataAccountV3 {
+        metadata: *metadata_info.key,
+        mint: *mint.key,
+        mint_authority: *mint_auth.key,
+        payer: *payer_info.key,
+        update_authority: (*mint_auth.key, true),
+        system_program: *system_info.key,
+        rent: Some(*rent_info.key),
+    };
+    let cmv3_args = CreateMetadataAccountV3InstructionArgs {
+        data,
+        is_mutable: true,
+        collection_details: None,
+    };
+    invoke_signed(
+        &cmv3.instruction(cmv3_args),
+        &[
+            metadata_info.clone(),
+            mint.clone(),
+            payer_info.clone(),
+            mint_auth.clone(),
+            metadata_program_info.clone(),
+            token_program_info.clone(),
+            system_info.clone(),
+            rent_info.clone(),
+        ],
+        &[auth_seeds],
+    )?;
+    Ok(())
+}
+
+/// Loads the metadata of `mint` after checking its address
+pub fn load_metadata(mint: &AccountInfo, metadata_info: &AccountInfo) -> Result<Metadata, ProgramError> {
+    let (metadata_key, _) = Metadata::find_pda(mint.key);
+    assert_eq_pubkey(metadata_info, &metadata_key)?;
+    Metadata::from_bytes(&metadata_info.data.borrow()).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+pub fn metadata_update_authority(mint: &AccountInfo, metadata_info: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    Ok(load_metadata(mint, metadata_info)?.update_authority)
+}
+
+/// Runs UpdateMetadataAccountV2 on the metadata of `mint`, signed by the
+/// `mint_auth` PDA or, for metadata created before it held the update
+/// authority, by the legacy update authority key
+pub fn mpl_update_metadata<'a>(
+    mint: &AccountInfo<'a>,
+    metadata_info: &AccountInfo<'a>,
+    update_authority_info: &AccountInfo<'a>,
+    metadata_program_info: &AccountInfo<'a>,
+    mint_auth: &AccountInfo<'a>,
+    auth_seeds: &[&[u8]],
+    args: UpdateMetadataAccountV2InstructionArgs,
+) -> ProgramResult {
+    let metadata = load_metadata(mint, metadata_info)?;
+    assert_eq_pubkey(update_authority_info, &metadata.update_authority)?;
+
+    let instruction = UpdateMetadataAccountV2 {
+        metadata: *metadata_info.key,
+        update_authority: *update_authority_info.key,
+    }
+    .instruction(args);
+    let account_infos = [
+        metadata_info.clone(),
+        update_authority_info.clone(),
+        metadata_program_info.clone(),
+    ];
+    if *update_authority_info.key == *mint_auth.key {
+        invoke_signed(&instruction, &account_infos, &[auth_seeds])?;
+    } else {
+        assert_signer(update_authority_info)?;
+        invoke(&instruction, &account_infos)?;
+    }
+    Ok(())
+}
@@ -66,32 +66,23 @@ fn create_dev() {
     ];
     let (metadata_key, _) = Pubkey::find_program_address(metadata_seeds, &metadata_program);
     println!(" metadata_key::::::{:?}", metadata_key.to_string());
+    let seeds = &[program_id.as_ref(), "config".as_bytes()];
+    let (config_info, _) = Pubkey::find_program_address(seeds, &program_id);
     let mut instructions = vec![];
     let tokenargs = CreateTokenArgs {
         name: "taozi".to_string(),
         symbol: "tz".to_string(),
         uri: "https://arweave.net/tuYnuXbs7MfkspgEUbvKuA_yhejGovNmlo5cS2WFkao".to_string(),
         decimals: 9,
+        pda_mint: false,
+        max_supply: None,
     };
 
-    let mut new_mint_instructions = vec![
-        create_account(
-            &signer.pubkey(),
-            &mint_pubkey,
-            client
-                .get_minimum_balance_for_rent_exemption(Mint::LEN)
-                .unwrap(),
-            Mint::LEN as u64,
-            &spl_token::id(),
-        ),
-        initialize_mint(&spl_token::id(), &mint_pubkey, &auth, Some(&auth), tokenargs.decimals).unwrap(),
-    ];
-    instructions.append(&mut new_mint_instructions);
-
     instructions.push(
         create_token(
             &program_id,
             &signer_pubkey,
+            &config_info,
             &token_info,
             &mint_pubkey,
             &auth,
@@ -131,6 +122,9 @@ fn config_dev() {
         /// Initialized state.
         /// Contract admin
         authority: auth,
+        timelock_delay: 0,
+        large_mint_threshold: None,
+        namespace: String::new(),
     };
 
     instructions.push(configure(&program_id, &signer_pubkey, &config_info, configargs).unwrap());
@@ -183,6 +177,13 @@ fn mint_dev(mint_pubkey: &Pubkey) {
 
     let token_account = get_associated_token_address(&signer_pubkey, &mint_pubkey);
     println!("token_account:::{:?}", token_account);
+    let blocklist_seeds = &[
+        program_id.as_ref(),
+        config_info.as_ref(),
+        signer_pubkey.as_ref(),
+        "blocklist".as_bytes(),
+    ];
+    let (blocklist_entry, _) = Pubkey::find_program_address(blocklist_seeds, &program_id);
 
     if client.get_balance(&token_account).unwrap() == 0 {
         let new_token_account_instruction =
@@ -192,6 +193,7 @@ fn mint_dev(mint_pubkey: &Pubkey) {
 
     let mintargs = MintArgs {
         amt: 1000,
+        unit: AmountUnit::Ui,
     };
 
     instructions.push(
@@ -204,6 +206,8 @@ fn mint_dev(mint_pubkey: &Pubkey) {
             &token_info,
             &auth,
             &token_program,
+            &blocklist_entry,
+            None,
             mintargs
         )
         .unwrap(),
@@ -257,9 +261,17 @@ fn burn_dev(mint_pubkey: &Pubkey) {
 
     let token_account = get_associated_token_address(&signer_pubkey, &mint_pubkey);
     println!("token_account:::{:?}", token_account);
+    let blocklist_seeds = &[
+        program_id.as_ref(),
+        config_info.as_ref(),
+        signer_pubkey.as_ref(),
+        "blocklist".as_bytes(),
+    ];
+    let (blocklist_entry, _) = Pubkey::find_program_address(blocklist_seeds, &program_id);
 
     let burnargs = BurnArgs {
         amt: 1000,
+        unit: AmountUnit::Ui,
     };
 
     instructions.push(
@@ -271,6 +283,8 @@ fn burn_dev(mint_pubkey: &Pubkey) {
             &token_account,
             &token_info,
             &token_program,
+            &blocklist_entry,
+            None,
             burnargs
         )
         .unwrap(),